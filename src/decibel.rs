@@ -36,6 +36,22 @@ pub fn coeff_to_db_clamped_neg_90_db_f32(coeff: f32) -> f32 {
     }
 }
 
+/// Returns the gain (in decibels) of the standard A-weighting curve (IEC 61672-1) at
+/// the given frequency in Hz.
+///
+/// This is normalized so that the gain at `1 kHz` is (approximately) `0.0` dB. A
+/// frequency of `0.0` Hz will return negative infinity.
+pub fn a_weighting_db_f32(freq_hz: f32) -> f32 {
+    let f2 = freq_hz * freq_hz;
+
+    let num = 12_194.0 * 12_194.0 * f2 * f2;
+    let den = (f2 + 20.6 * 20.6)
+        * ((f2 + 107.7 * 107.7) * (f2 + 737.9 * 737.9)).sqrt()
+        * (f2 + 12_194.0 * 12_194.0);
+
+    coeff_to_db_f32(num / den) + 2.00
+}
+
 /// Returns the raw amplitude (coefficient) from the given decibel value.
 #[inline]
 pub fn db_to_coeff_f64(db: f64) -> f64 {
@@ -73,3 +89,35 @@ pub fn coeff_to_db_clamped_neg_90_db_f64(coeff: f64) -> f64 {
         coeff_to_db_f64(coeff)
     }
 }
+
+/// Returns the gain (in decibels) of the standard A-weighting curve (IEC 61672-1) at
+/// the given frequency in Hz.
+///
+/// This is normalized so that the gain at `1 kHz` is (approximately) `0.0` dB. A
+/// frequency of `0.0` Hz will return negative infinity.
+pub fn a_weighting_db_f64(freq_hz: f64) -> f64 {
+    let f2 = freq_hz * freq_hz;
+
+    let num = 12_194.0 * 12_194.0 * f2 * f2;
+    let den = (f2 + 20.6 * 20.6)
+        * ((f2 + 107.7 * 107.7) * (f2 + 737.9 * 737.9)).sqrt()
+        * (f2 + 12_194.0 * 12_194.0);
+
+    coeff_to_db_f64(num / den) + 2.00
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_a_weighting() {
+        assert!(a_weighting_db_f32(1_000.0).abs() < 0.01);
+        assert!((a_weighting_db_f32(100.0) - -19.1).abs() < 0.05);
+        assert!((a_weighting_db_f32(10_000.0) - -2.5).abs() < 0.05);
+
+        assert!(a_weighting_db_f64(1_000.0).abs() < 0.01);
+        assert!((a_weighting_db_f64(100.0) - -19.1).abs() < 0.05);
+        assert!((a_weighting_db_f64(10_000.0) - -2.5).abs() < 0.05);
+    }
+}
@@ -0,0 +1,142 @@
+//! Interpolation functions shared by anything that needs to read between samples
+//! or points.
+
+/// Linear interpolation between `a` and `b`.
+///
+/// * `t` - The position between `a` and `b`, where `0.0` returns `a` and `1.0` returns `b`.
+#[inline]
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + ((b - a) * t)
+}
+
+/// Cubic Hermite interpolation between the points `p0` and `p1` with the tangents `m0`
+/// and `m1` at those points.
+///
+/// * `t` - The position between `p0` and `p1`, where `0.0` returns `p0` and `1.0` returns `p1`.
+#[inline]
+pub fn cubic_hermite(p0: f32, m0: f32, p1: f32, m1: f32, t: f32) -> f32 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let h00 = (2.0 * t3) - (3.0 * t2) + 1.0;
+    let h10 = t3 - (2.0 * t2) + t;
+    let h01 = (-2.0 * t3) + (3.0 * t2);
+    let h11 = t3 - t2;
+
+    (h00 * p0) + (h10 * m0) + (h01 * p1) + (h11 * m1)
+}
+
+/// Catmull-Rom spline interpolation between `y1` and `y2`, using the neighboring points
+/// `y0` and `y3` to compute the tangents.
+///
+/// This is the commonly used "4-point, 3rd-order Hermite" interpolator for audio.
+///
+/// * `t` - The position between `y1` and `y2`, where `0.0` returns `y1` and `1.0` returns `y2`.
+#[inline]
+pub fn catmull_rom(y0: f32, y1: f32, y2: f32, y3: f32, t: f32) -> f32 {
+    cubic_hermite(y1, (y2 - y0) * 0.5, y2, (y3 - y1) * 0.5, t)
+}
+
+/// Read from `src` at a non-unity playback rate (for varispeed and tape-stop effects),
+/// linearly interpolating between samples.
+///
+/// * `src` - The source samples.
+/// * `rate` - How far to advance in `src` for each output sample (`1.0` is the original speed).
+/// * `start_frac` - The (fractional) position in `src` of the first output sample.
+/// * `out` - The buffer to write the resampled output into.
+///
+/// Any position outside of `src` reads as `0.0`.
+///
+/// Returns the position in `src` after the last output sample, which can be passed as
+/// `start_frac` for the next block.
+pub fn varispeed_read(src: &[f32], rate: f64, start_frac: f64, out: &mut [f32]) -> f64 {
+    let sample_at = |i: f64| -> f32 {
+        if i >= 0.0 && i < src.len() as f64 {
+            src[i as usize]
+        } else {
+            0.0
+        }
+    };
+
+    let mut pos = start_frac;
+    for o in out.iter_mut() {
+        let i = pos.floor();
+        *o = lerp(sample_at(i), sample_at(i + 1.0), (pos - i) as f32);
+
+        pos += rate;
+    }
+
+    pos
+}
+
+/// The same as `varispeed_read()`, but for a stereo pair of channels that are read at
+/// the same position.
+///
+/// Only `min(out_l.len(), out_r.len())` frames are written to each channel, so that the
+/// two channels always stay in sync.
+///
+/// Returns the position in the source after the last output sample.
+pub fn varispeed_read_stereo(
+    src_l: &[f32],
+    src_r: &[f32],
+    rate: f64,
+    start_frac: f64,
+    out_l: &mut [f32],
+    out_r: &mut [f32],
+) -> f64 {
+    debug_assert_eq!(out_l.len(), out_r.len());
+
+    let frames = out_l.len().min(out_r.len());
+
+    let pos = varispeed_read(src_l, rate, start_frac, &mut out_l[..frames]);
+    varispeed_read(src_r, rate, start_frac, &mut out_r[..frames]);
+
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interp() {
+        assert_eq!(lerp(0.0, 1.0, 0.25), 0.25);
+        assert_eq!(lerp(2.0, -2.0, 0.25), 1.0);
+
+        assert_eq!(cubic_hermite(0.0, 0.0, 1.0, 0.0, 0.0), 0.0);
+        assert_eq!(cubic_hermite(0.0, 0.0, 1.0, 0.0, 0.25), 0.15625);
+        assert_eq!(cubic_hermite(0.0, 0.0, 1.0, 0.0, 1.0), 1.0);
+
+        // Catmull-Rom reproduces a straight line exactly.
+        assert_eq!(catmull_rom(0.0, 1.0, 2.0, 3.0, 0.25), 1.25);
+        assert_eq!(catmull_rom(0.0, 0.0, 1.0, 1.0, 0.25), 0.203125);
+    }
+
+    #[test]
+    fn test_varispeed_read() {
+        let src = [1.0, 2.0, 3.0, 4.0];
+
+        let mut out = [0.0; 4];
+        let pos = varispeed_read(&src, 1.0, 0.0, &mut out);
+        assert_eq!(out, src);
+        assert_eq!(pos, 4.0);
+
+        let mut out = [0.0; 8];
+        let pos = varispeed_read(&src, 0.5, 0.0, &mut out);
+        assert_eq!(out, [1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 2.0]);
+        assert_eq!(pos, 4.0);
+
+        // Continuing from the returned position reads past the end as silence.
+        let mut out = [1.0; 2];
+        varispeed_read(&src, 0.5, pos, &mut out);
+        assert_eq!(out, [0.0, 0.0]);
+
+        let mut out_l = [0.0; 4];
+        let mut out_r = [0.0; 4];
+        let src_r = [-1.0, -2.0, -3.0, -4.0];
+        let pos = varispeed_read_stereo(&src, &src_r, 0.5, 1.0, &mut out_l, &mut out_r);
+        assert_eq!(out_l, [2.0, 2.5, 3.0, 3.5]);
+        assert_eq!(out_r, [-2.0, -2.5, -3.0, -3.5]);
+        assert_eq!(pos, 3.0);
+    }
+}
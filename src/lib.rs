@@ -1,6 +1,10 @@
 pub mod atomic;
 pub mod decibel;
 pub mod declick;
+pub mod interp;
+pub mod meter;
 pub mod parameter;
 pub mod smooth;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod time;
@@ -0,0 +1,90 @@
+use crate::smooth::one_pole_coeff_f32;
+use crate::time::{SampleRate, SecondsF64};
+
+/// Attack/release ballistics for a level meter.
+///
+/// The absolute value of the input is followed by a one-pole low-pass filter, using the
+/// attack period when the level is rising and the release period when it is falling.
+#[derive(Debug, Clone, Copy)]
+pub struct MeterBallistics {
+    attack_b: f32,
+    release_b: f32,
+
+    value: f32,
+}
+
+impl MeterBallistics {
+    pub fn new(attack: SecondsF64, release: SecondsF64, sample_rate: SampleRate) -> Self {
+        Self {
+            attack_b: one_pole_coeff_f32(sample_rate, attack),
+            release_b: one_pole_coeff_f32(sample_rate, release),
+            value: 0.0,
+        }
+    }
+
+    /// Set the period used when the level is rising.
+    pub fn set_attack(&mut self, seconds: SecondsF64, sample_rate: SampleRate) {
+        self.attack_b = one_pole_coeff_f32(sample_rate, seconds);
+    }
+
+    /// Set the period used when the level is falling.
+    pub fn set_release(&mut self, seconds: SecondsF64, sample_rate: SampleRate) {
+        self.release_b = one_pole_coeff_f32(sample_rate, seconds);
+    }
+
+    /// Process a block of samples, returning the meter value at the end of the block.
+    pub fn process_block(&mut self, input: &[f32]) -> f32 {
+        let mut value = self.value;
+
+        for s in input.iter() {
+            let level = s.abs();
+            let b = if level > value {
+                self.attack_b
+            } else {
+                self.release_b
+            };
+
+            value = level + (b * (value - level));
+        }
+
+        self.value = value;
+        value
+    }
+
+    /// The current meter value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn reset(&mut self) {
+        self.value = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meter_ballistics() {
+        let sample_rate = SampleRate::new(48_000.0);
+        let mut meter = MeterBallistics::new(SecondsF64(0.001), SecondsF64(0.3), sample_rate);
+
+        // 10 ms of a full-scale step.
+        let attack = meter.process_block(&[1.0; 480]);
+        assert!(attack > 0.99);
+
+        // 10 ms of silence.
+        let release = meter.process_block(&[0.0; 480]);
+        assert!(release > 0.9);
+
+        // After one release period, the value has decayed by a factor of `e`.
+        let mut meter = MeterBallistics::new(SecondsF64(0.0), SecondsF64(0.3), sample_rate);
+        assert_eq!(meter.process_block(&[-1.0]), 1.0);
+        let value = meter.process_block(&vec![0.0; 14_400]);
+        assert!((value - (-1.0f32).exp()).abs() < 1.0e-3);
+
+        meter.reset();
+        assert_eq!(meter.value(), 0.0);
+    }
+}
@@ -55,6 +55,24 @@ pub enum Gradient {
     Exponential,
 }
 
+impl Gradient {
+    /// A `Power(3.0)` curve, which is a good default for volume faders that deal with raw
+    /// amplitude (as opposed to decibels).
+    ///
+    /// This gives more resolution to the upper range of the fader where changes in volume
+    /// are most noticeable. For example, `0.5` normalized maps to `0.125` of the range.
+    pub const fn audio_taper() -> Gradient {
+        Gradient::Power(3.0)
+    }
+
+    /// A `Power(1.0 / 3.0)` curve, the inverse of `Gradient::audio_taper()`.
+    ///
+    /// For example, `0.125` normalized maps to `0.5` of the range.
+    pub const fn reverse_audio_taper() -> Gradient {
+        Gradient::Power(1.0 / 3.0)
+    }
+}
+
 /// The unit of this parameter. This signifies how the value displayed to the end user should
 /// differ from the actual value used in DSP.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -120,7 +138,7 @@ pub struct ParamF32 {
     gradient: Gradient,
     unit: Unit,
 
-    shared_normalized: Arc<AtomicF32>,
+    shared_normalized: Option<Arc<AtomicF32>>,
     normalized: f32,
 
     value: f32,
@@ -143,12 +161,13 @@ impl ParamF32 {
     /// * unit - The [`Unit`] that signifies how the value displayed to the end user should
     /// differ from the actual value used in DSP.
     /// * smooth_secs: The period of the low-pass parameter smoothing filter (for declicking). You
-    /// may use `ParamF32::DEFAULT_SMOOTH_SECS` as a good default.
+    /// may use `ParamF32::DEFAULT_SMOOTH_SECS` as a good default, or a [`SmoothTime`] preset.
     /// * sample_rate: The sample rate of this process. This is used for the low-pass parameter
     /// smoothing filter.
     ///
     /// [`Gradient`]: enum.Gradient.html
     /// [`Unit`]: enum.Unit.html
+    /// [`SmoothTime`]: ../smooth/enum.SmoothTime.html
     pub fn from_value(
         value: f32,
         default_value: f32,
@@ -156,10 +175,12 @@ impl ParamF32 {
         max: f32,
         gradient: Gradient,
         unit: Unit,
-        smooth_secs: SecondsF64,
+        smooth_secs: impl Into<SecondsF64>,
         sample_rate: SampleRate,
         max_blocksize: usize,
     ) -> (Self, ParamF32Handle) {
+        let smooth_secs = smooth_secs.into();
+
         let normalized = value_to_normalized_f32(value, min, max, gradient);
 
         let handle_value = normalized_to_value_f32(normalized, min, max, gradient);
@@ -179,7 +200,7 @@ impl ParamF32 {
                 max,
                 gradient,
                 unit,
-                shared_normalized: Arc::clone(&shared_normalized),
+                shared_normalized: Some(Arc::clone(&shared_normalized)),
                 normalized,
                 value: rt_value,
                 default_value,
@@ -209,12 +230,13 @@ impl ParamF32 {
     /// * unit - The [`Unit`] that signifies how the value displayed to the end user should
     /// differ from the actual value used in DSP.
     /// * smooth_secs: The period of the low-pass parameter smoothing filter (for declicking). You
-    /// may use `ParamF32::DEFAULT_SMOOTH_SECS` as a good default.
+    /// may use `ParamF32::DEFAULT_SMOOTH_SECS` as a good default, or a [`SmoothTime`] preset.
     /// * sample_rate: The sample rate of this process. This is used for the low-pass parameter
     /// smoothing filter.
     ///
     /// [`Gradient`]: enum.Gradient.html
     /// [`Unit`]: enum.Unit.html
+    /// [`SmoothTime`]: ../smooth/enum.SmoothTime.html
     pub fn from_normalized(
         normalized: f32,
         default_value: f32,
@@ -222,10 +244,12 @@ impl ParamF32 {
         max_value: f32,
         gradient: Gradient,
         unit: Unit,
-        smooth_secs: SecondsF64,
+        smooth_secs: impl Into<SecondsF64>,
         sample_rate: SampleRate,
         max_blocksize: usize,
     ) -> (Self, ParamF32Handle) {
+        let smooth_secs = smooth_secs.into();
+
         let normalized = normalized.clamp(0.0, 1.0);
 
         let shared_normalized = Arc::new(AtomicF32::new(normalized));
@@ -245,7 +269,7 @@ impl ParamF32 {
                 max: max_value,
                 gradient,
                 unit,
-                shared_normalized: Arc::clone(&shared_normalized),
+                shared_normalized: Some(Arc::clone(&shared_normalized)),
                 normalized,
                 value: rt_value,
                 default_value,
@@ -263,11 +287,76 @@ impl ParamF32 {
         )
     }
 
+    /// Create a Parameter from its (de-normalized) value without a corresponding
+    /// [`ParamF32Handle`].
+    ///
+    /// Unlike `ParamF32::from_value()`, this does not allocate a shared atomic value, so
+    /// there is no atomic load on every call to `ParamF32::smoothed()`. This is useful for
+    /// offline/single-threaded rendering where the value is only ever updated through this
+    /// struct directly (i.e. with `ParamF32::set_value()`).
+    ///
+    /// * value - The initial (de-normalized) value of the parameter.
+    /// * default_value - The default (de-normalized) value of the parameter.
+    /// * min - The minimum (de-normalized) value of the parameter.
+    /// * max - The maximum (de-normalized) value of the parameter.
+    /// * gradient - The [`Gradient`] mapping used when converting from the normalized value
+    /// in the range `[0.0, 1.0]` to the desired value.
+    /// * unit - The [`Unit`] that signifies how the value displayed to the end user should
+    /// differ from the actual value used in DSP.
+    /// * smooth_secs: The period of the low-pass parameter smoothing filter (for declicking). You
+    /// may use `ParamF32::DEFAULT_SMOOTH_SECS` as a good default, or a [`SmoothTime`] preset.
+    /// * sample_rate: The sample rate of this process. This is used for the low-pass parameter
+    /// smoothing filter.
+    ///
+    /// [`ParamF32Handle`]: struct.ParamF32Handle.html
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`Unit`]: enum.Unit.html
+    /// [`SmoothTime`]: ../smooth/enum.SmoothTime.html
+    pub fn from_value_local(
+        value: f32,
+        default_value: f32,
+        min: f32,
+        max: f32,
+        gradient: Gradient,
+        unit: Unit,
+        smooth_secs: impl Into<SecondsF64>,
+        sample_rate: SampleRate,
+        max_blocksize: usize,
+    ) -> Self {
+        let smooth_secs = smooth_secs.into();
+
+        let normalized = value_to_normalized_f32(value, min, max, gradient);
+
+        let handle_value = normalized_to_value_f32(normalized, min, max, gradient);
+        let rt_value = match unit {
+            Unit::Decibels => db_to_coeff_clamped_neg_90_db_f32(handle_value),
+            _ => handle_value,
+        };
+
+        let mut smoothed = SmoothF32::new(rt_value, max_blocksize);
+        smoothed.set_speed(sample_rate, smooth_secs);
+
+        Self {
+            min,
+            max,
+            gradient,
+            unit,
+            shared_normalized: None,
+            normalized,
+            value: rt_value,
+            default_value,
+            smoothed,
+            smooth_secs,
+        }
+    }
+
     /// Set the (de-normalized) value of this parameter.
     pub fn set_value(&mut self, value: f32) {
         if self.value != value {
             self.normalized = value_to_normalized_f32(value, self.min, self.max, self.gradient);
-            self.shared_normalized.set(self.normalized);
+            if let Some(shared_normalized) = &self.shared_normalized {
+                shared_normalized.set(self.normalized);
+            }
 
             let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
             self.value = match self.unit {
@@ -283,7 +372,9 @@ impl ParamF32 {
     pub fn set_normalized(&mut self, normalized: f32) {
         if self.normalized != normalized {
             self.normalized = normalized.clamp(0.0, 1.0);
-            self.shared_normalized.set(self.normalized);
+            if let Some(shared_normalized) = &self.shared_normalized {
+                shared_normalized.set(self.normalized);
+            }
 
             let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
             self.value = match self.unit {
@@ -298,7 +389,9 @@ impl ParamF32 {
     /// Reset this parameter (without any smoothing) to the given (de-normalized) value.
     pub fn reset_from_value(&mut self, value: f32) {
         self.normalized = value_to_normalized_f32(value, self.min, self.max, self.gradient);
-        self.shared_normalized.set(self.normalized);
+        if let Some(shared_normalized) = &self.shared_normalized {
+            shared_normalized.set(self.normalized);
+        }
 
         let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
         self.value = match self.unit {
@@ -312,7 +405,9 @@ impl ParamF32 {
     /// Reset this parameter (without any smoothing) to the given normalized value in the range `[0.0, 1.0]`.
     pub fn reset_from_normalized(&mut self, normalized: f32) {
         self.normalized = normalized.clamp(0.0, 1.0);
-        self.shared_normalized.set(self.normalized);
+        if let Some(shared_normalized) = &self.shared_normalized {
+            shared_normalized.set(self.normalized);
+        }
 
         let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
         self.value = match self.unit {
@@ -329,18 +424,85 @@ impl ParamF32 {
     }
 
     /// Get the smoothed buffer of values for use in DSP.
+    ///
+    /// The value shared with the [`ParamF32Handle`] is read once per call with
+    /// `Ordering::Relaxed`. This is sufficient because the shared normalized value is a
+    /// single self-contained `f32` and no other memory is published alongside it. The only
+    /// guarantee needed is that a new value is observed *eventually*, which in practice
+    /// means a change made by the handle is picked up at the start of the next block.
+    ///
+    /// Do not rely on this value to synchronize any other state between threads.
+    ///
+    /// ```
+    /// use meadowlark_core_types::parameter::{Gradient, ParamF32, Unit, DEFAULT_SMOOTH_SECS};
+    /// use meadowlark_core_types::time::SampleRate;
+    ///
+    /// let (mut param, handle) = ParamF32::from_value(
+    ///     0.0,
+    ///     0.0,
+    ///     0.0,
+    ///     1.0,
+    ///     Gradient::Linear,
+    ///     Unit::Generic,
+    ///     DEFAULT_SMOOTH_SECS,
+    ///     SampleRate::default(),
+    ///     64,
+    /// );
+    ///
+    /// // The UI thread stores a new value...
+    /// handle.set_normalized(1.0);
+    ///
+    /// // ...and the DSP thread picks it up at the start of its next block.
+    /// let _ = param.smoothed(64);
+    /// assert_eq!(param.host_get_normalized(), 1.0);
+    /// ```
+    ///
+    /// [`ParamF32Handle`]: struct.ParamF32Handle.html
     pub fn smoothed(&mut self, frames: usize) -> SmoothOutputF32 {
-        let new_normalized = self.shared_normalized.get();
-        if self.normalized != new_normalized {
-            self.normalized = new_normalized;
+        if let Some(shared_normalized) = &self.shared_normalized {
+            let new_normalized = shared_normalized.get();
+            if self.normalized != new_normalized {
+                self.normalized = new_normalized;
+
+                let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
+                self.value = match self.unit {
+                    Unit::Decibels => db_to_coeff_clamped_neg_90_db_f32(v),
+                    _ => v,
+                };
+
+                self.smoothed.set(self.value);
+            }
+        }
 
-            let v = normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient);
-            self.value = match self.unit {
-                Unit::Decibels => db_to_coeff_clamped_neg_90_db_f32(v),
-                _ => v,
-            };
+        self.smoothed.process(frames);
+        self.smoothed.update_status();
 
-            self.smoothed.set(self.value);
+        self.smoothed.output()
+    }
+
+    /// The same as `ParamF32::smoothed()`, except that the normalized value is mapped using
+    /// the given precomputed [`GradientTableF32`] instead of this parameter's [`Gradient`].
+    ///
+    /// This avoids calling any transcendental functions when the value changes, which is
+    /// useful when the parameter is being modulated every block. The table must be created
+    /// with `ParamF32::gradient_table()`, since it holds the values as used in DSP (i.e.
+    /// the raw amplitude coefficients for a parameter with `Unit::Decibels`).
+    ///
+    /// [`GradientTableF32`]: struct.GradientTableF32.html
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn smoothed_mapped(
+        &mut self,
+        frames: usize,
+        table: &GradientTableF32,
+    ) -> SmoothOutputF32<'_> {
+        if let Some(shared_normalized) = &self.shared_normalized {
+            let new_normalized = shared_normalized.get();
+            if self.normalized != new_normalized {
+                self.normalized = new_normalized;
+
+                self.value = table.normalized_to_value(self.normalized);
+                self.smoothed.set(self.value);
+            }
         }
 
         self.smoothed.process(frames);
@@ -349,6 +511,25 @@ impl ParamF32 {
         self.smoothed.output()
     }
 
+    /// Create a [`GradientTableF32`] with `size` points for this parameter's range and
+    /// [`Gradient`], for use with `ParamF32::smoothed_mapped()`.
+    ///
+    /// The values in the table are already converted to the values used in DSP (see
+    /// `ParamF32::coeff()`), so the [`Unit`] conversion does not need to be calculated
+    /// when mapping.
+    ///
+    /// [`GradientTableF32`]: struct.GradientTableF32.html
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`Unit`]: enum.Unit.html
+    pub fn gradient_table(&self, size: usize) -> GradientTableF32 {
+        let mut table = GradientTableF32::new(self.min, self.max, self.gradient, size);
+        for v in table.table.iter_mut() {
+            *v = self.unit.unit_to_dsp_f32(*v);
+        }
+
+        table
+    }
+
     /// Update the sample rate (used for the parameter smoothing LPF).
     pub fn set_sample_rate(&mut self, sample_rate: SampleRate) {
         self.smoothed.set_speed(sample_rate, self.smooth_secs);
@@ -421,20 +602,81 @@ impl ParamF32 {
     /// value for DSP. To get the latest value for DSP please use `ParamF32::smoothed()`
     /// instead.
     ///
+    /// This is in the same unit as the values given to this parameter's constructors and
+    /// `set_value()` (i.e. decibels for a parameter with `Unit::Decibels`). Use
+    /// `ParamF32::coeff()` to get the corresponding value used in DSP.
+    ///
     /// Please note that this should be called *after* calling `ParamF32::smoothed()`
     /// if you need the latest value from the corresponding [`ParamF32Handle`],
     /// otherwise this may not return the latest value.
     ///
     /// [`ParamF32Handle`]: struct.ParamF32Handle.html
     pub fn host_get_value(&self) -> f32 {
+        normalized_to_value_f32(self.normalized, self.min, self.max, self.gradient)
+    }
+
+    /// The current (un-smoothed) value of this parameter as used in DSP.
+    ///
+    /// For a parameter with `Unit::Decibels`, the values given to/returned from this
+    /// parameter's constructors, `set_value()`, and the [`ParamF32Handle`] are in decibels,
+    /// but the value used in DSP (and the values in `ParamF32::smoothed()`) is the raw
+    /// amplitude coefficient. This returns that coefficient, whereas
+    /// `ParamF32::host_get_value()` returns the value in decibels. For any other unit, this
+    /// is the same as `ParamF32::host_get_value()`.
+    ///
+    /// Please note that this should be called *after* calling `ParamF32::smoothed()`
+    /// if you need the latest value from the corresponding [`ParamF32Handle`],
+    /// otherwise this may not return the latest value.
+    ///
+    /// [`ParamF32Handle`]: struct.ParamF32Handle.html
+    pub fn coeff(&self) -> f32 {
         self.value
     }
 
     /// Get the shared normalized float value.
     ///
     /// This can be useful to integrate with various plugin APIs.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if this parameter was created with `ParamF32::from_value_local()`.
+    /// Use `ParamF32::try_shared_normalized()` if that may be the case.
     pub fn shared_normalized(&self) -> Arc<AtomicF32> {
-        Arc::clone(&self.shared_normalized)
+        self.try_shared_normalized()
+            .expect("ParamF32 created with from_value_local() has no shared normalized value")
+    }
+
+    /// Get the shared normalized float value, or `None` if this parameter was created
+    /// with `ParamF32::from_value_local()`.
+    pub fn try_shared_normalized(&self) -> Option<Arc<AtomicF32>> {
+        self.shared_normalized.as_ref().map(Arc::clone)
+    }
+
+    /// Whether or not this parameter was created with `ParamF32::from_value_local()`,
+    /// meaning it has no shared value and no corresponding [`ParamF32Handle`].
+    ///
+    /// [`ParamF32Handle`]: struct.ParamF32Handle.html
+    pub fn is_local(&self) -> bool {
+        self.shared_normalized.is_none()
+    }
+
+    /// Map each normalized value in `src` (in the range `[0.0, 1.0]`) to the corresponding
+    /// (un-normalized) value, writing the results into `dst`.
+    ///
+    /// This is equivalent to calling `normalized_to_value_f32()` on each value, and is
+    /// useful for things like rendering a parameter's curve in a UI.
+    ///
+    /// Only `min(src.len(), dst.len())` values will be processed.
+    pub fn map_normalized_slice(
+        src: &[f32],
+        dst: &mut [f32],
+        min: f32,
+        max: f32,
+        gradient: Gradient,
+    ) {
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d = normalized_to_value_f32(*s, min, max, gradient);
+        }
     }
 }
 
@@ -470,6 +712,19 @@ impl ParamF32Handle {
         )
     }
 
+    /// The value of this parameter as used in DSP.
+    ///
+    /// For a parameter with `Unit::Decibels`, `ParamF32Handle::value()` is in decibels,
+    /// while this returns the corresponding raw amplitude coefficient (the same value that
+    /// `ParamF32::coeff()` returns). For any other unit, this is the same as
+    /// `ParamF32Handle::value()`.
+    ///
+    /// Please note that this is calculated from the shared normalized value every time, so
+    /// avoid calling this every frame if you can.
+    pub fn coeff(&self) -> f32 {
+        self.unit.unit_to_dsp_f32(self.value())
+    }
+
     /// The (un-normalized) default value of the parameter.
     pub fn default_value(&self) -> f32 {
         self.default_value
@@ -485,6 +740,12 @@ impl ParamF32Handle {
     /// Please note that this will ***NOT*** automatically notify the host of the value change
     /// if you are using this inside a plugin spec such as VST. It is intended for you use your
     /// own method for achieving this.
+    ///
+    /// The value is stored with `Ordering::Relaxed`. The corresponding [`ParamF32`] will see
+    /// the new value the next time it calls `ParamF32::smoothed()`, but no ordering is
+    /// guaranteed relative to any other writes made by this thread.
+    ///
+    /// [`ParamF32`]: struct.ParamF32.html
     pub fn set_normalized(&self, normalized: f32) {
         self.shared_normalized.set(normalized.clamp(0.0, 1.0));
     }
@@ -572,19 +833,7 @@ pub fn normalized_to_value_f32(normalized: f32, min: f32, max: f32, gradient: Gr
 
         Gradient::Power(exponent) => map(normalized.powf(exponent)),
 
-        Gradient::Exponential => {
-            if normalized == 0.0 {
-                return min;
-            }
-
-            if normalized == 1.0 {
-                return max;
-            }
-
-            let minl = min.log2();
-            let range = max.log2() - minl;
-            2.0f32.powf((normalized * range) + minl)
-        }
+        Gradient::Exponential => normalized_log_to_freq_f32(normalized, min, max),
     }
 }
 
@@ -607,11 +856,105 @@ pub fn value_to_normalized_f32(value: f32, min: f32, max: f32, gradient: Gradien
 
         Gradient::Power(exponent) => unmap(value).powf(1.0 / exponent),
 
-        Gradient::Exponential => {
-            let minl = min.log2();
-            let range = max.log2() - minl;
-            (value.log2() - minl) / range
+        Gradient::Exponential => freq_to_normalized_log_f32(value, min, max),
+    }
+}
+
+/// Map the given frequency in Hz to its position in the range `[0.0, 1.0]` on a logarithmic
+/// axis spanning `[min_hz, max_hz]`.
+///
+/// This uses the same math as [`Gradient::Exponential`], so analyzers and parameters can
+/// share the same axis.
+///
+/// [`Gradient::Exponential`]: enum.Gradient.html#variant.Exponential
+pub fn freq_to_normalized_log_f32(hz: f32, min_hz: f32, max_hz: f32) -> f32 {
+    if hz <= min_hz {
+        return 0.0;
+    }
+
+    if hz >= max_hz {
+        return 1.0;
+    }
+
+    let minl = min_hz.log2();
+    let range = max_hz.log2() - minl;
+    (hz.log2() - minl) / range
+}
+
+/// Map the given position in the range `[0.0, 1.0]` on a logarithmic axis spanning
+/// `[min_hz, max_hz]` back to its frequency in Hz.
+///
+/// This is the inverse of `freq_to_normalized_log_f32()`.
+pub fn normalized_log_to_freq_f32(normalized: f32, min_hz: f32, max_hz: f32) -> f32 {
+    let normalized = normalized.clamp(0.0, 1.0);
+
+    if normalized == 0.0 {
+        return min_hz;
+    }
+
+    if normalized == 1.0 {
+        return max_hz;
+    }
+
+    let minl = min_hz.log2();
+    let range = max_hz.log2() - minl;
+    2.0f32.powf((normalized * range) + minl)
+}
+
+/// A precomputed lookup table of a [`Gradient`] mapping.
+///
+/// `Gradient::Power` and `Gradient::Exponential` call `powf()` on every conversion, which
+/// can be too slow when mapping modulation on a per-sample basis. This table samples the
+/// mapping once up front and then uses linear interpolation between the points, so no
+/// transcendental functions are called in the hot path.
+///
+/// Note that this is an approximation. The more points in the table, the more accurate
+/// the approximation will be.
+///
+/// [`Gradient`]: enum.Gradient.html
+#[derive(Debug, Clone)]
+pub struct GradientTableF32 {
+    table: Vec<f32>,
+}
+
+impl GradientTableF32 {
+    /// Create a new lookup table for the given mapping.
+    ///
+    /// * min - The minimum (de-normalized) value of the mapping.
+    /// * max - The maximum (de-normalized) value of the mapping.
+    /// * gradient - The [`Gradient`] mapping to precompute.
+    /// * size - The number of points in the table (constrained to be at least `2`).
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn new(min: f32, max: f32, gradient: Gradient, size: usize) -> Self {
+        let size = size.max(2);
+        let last = (size - 1) as f32;
+
+        let table = (0..size)
+            .map(|i| normalized_to_value_f32(i as f32 / last, min, max, gradient))
+            .collect();
+
+        Self { table }
+    }
+
+    /// Convert the given normalized value in the range `[0.0, 1.0]` into the
+    /// corresponding (approximated) value.
+    #[inline]
+    pub fn normalized_to_value(&self, normalized: f32) -> f32 {
+        let pos = normalized.clamp(0.0, 1.0) * (self.table.len() - 1) as f32;
+
+        let i = pos as usize;
+        if i >= self.table.len() - 1 {
+            return self.table[self.table.len() - 1];
         }
+
+        let fract = pos - i as f32;
+        self.table[i] + ((self.table[i + 1] - self.table[i]) * fract)
+    }
+
+    /// The number of points in this table.
+    pub fn size(&self) -> usize {
+        self.table.len()
     }
 }
 
@@ -647,12 +990,13 @@ impl ParamF64 {
     /// * unit - The [`Unit`] that signifies how the value displayed to the end user should
     /// differ from the actual value used in DSP.
     /// * smooth_secs: The period of the low-pass parameter smoothing filter (for declicking). You
-    /// may use `ParamF64::DEFAULT_SMOOTH_SECS` as a good default.
+    /// may use `ParamF64::DEFAULT_SMOOTH_SECS` as a good default, or a [`SmoothTime`] preset.
     /// * sample_rate: The sample rate of this process. This is used for the low-pass parameter
     /// smoothing filter.
     ///
     /// [`Gradient`]: enum.Gradient.html
     /// [`Unit`]: enum.Unit.html
+    /// [`SmoothTime`]: ../smooth/enum.SmoothTime.html
     pub fn from_value(
         value: f64,
         default_value: f64,
@@ -660,10 +1004,12 @@ impl ParamF64 {
         max: f64,
         gradient: Gradient,
         unit: Unit,
-        smooth_secs: SecondsF64,
+        smooth_secs: impl Into<SecondsF64>,
         sample_rate: SampleRate,
         max_blocksize: usize,
     ) -> (Self, ParamF64Handle) {
+        let smooth_secs = smooth_secs.into();
+
         let normalized = value_to_normalized_f64(value, min, max, gradient);
 
         let handle_value = normalized_to_value_f64(normalized, min, max, gradient);
@@ -713,12 +1059,13 @@ impl ParamF64 {
     /// * unit - The [`Unit`] that signifies how the value displayed to the end user should
     /// differ from the actual value used in DSP.
     /// * smooth_secs: The period of the low-pass parameter smoothing filter (for declicking). You
-    /// may use `ParamF64::DEFAULT_SMOOTH_SECS` as a good default.
+    /// may use `ParamF64::DEFAULT_SMOOTH_SECS` as a good default, or a [`SmoothTime`] preset.
     /// * sample_rate: The sample rate of this process. This is used for the low-pass parameter
     /// smoothing filter.
     ///
     /// [`Gradient`]: enum.Gradient.html
     /// [`Unit`]: enum.Unit.html
+    /// [`SmoothTime`]: ../smooth/enum.SmoothTime.html
     pub fn from_normalized(
         normalized: f64,
         default_value: f64,
@@ -726,10 +1073,12 @@ impl ParamF64 {
         max_value: f64,
         gradient: Gradient,
         unit: Unit,
-        smooth_secs: SecondsF64,
+        smooth_secs: impl Into<SecondsF64>,
         sample_rate: SampleRate,
         max_blocksize: usize,
     ) -> (Self, ParamF64Handle) {
+        let smooth_secs = smooth_secs.into();
+
         let normalized = normalized.clamp(0.0, 1.0);
 
         let shared_normalized = Arc::new(AtomicF64::new(normalized));
@@ -833,6 +1182,16 @@ impl ParamF64 {
     }
 
     /// Get the smoothed buffer of values for use in DSP.
+    ///
+    /// The value shared with the [`ParamF64Handle`] is read once per call with
+    /// `Ordering::Relaxed`. This is sufficient because the shared normalized value is a
+    /// single self-contained `f64` and no other memory is published alongside it. The only
+    /// guarantee needed is that a new value is observed *eventually*, which in practice
+    /// means a change made by the handle is picked up at the start of the next block.
+    ///
+    /// Do not rely on this value to synchronize any other state between threads.
+    ///
+    /// [`ParamF64Handle`]: struct.ParamF64Handle.html
     pub fn smoothed(&mut self, frames: usize) -> SmoothOutputF64 {
         let new_normalized = self.shared_normalized.get();
         if self.normalized != new_normalized {
@@ -853,6 +1212,54 @@ impl ParamF64 {
         self.smoothed.output()
     }
 
+    /// The same as `ParamF64::smoothed()`, except that the normalized value is mapped using
+    /// the given precomputed [`GradientTableF64`] instead of this parameter's [`Gradient`].
+    ///
+    /// This avoids calling any transcendental functions when the value changes, which is
+    /// useful when the parameter is being modulated every block. The table must be created
+    /// with `ParamF64::gradient_table()`, since it holds the values as used in DSP (i.e.
+    /// the raw amplitude coefficients for a parameter with `Unit::Decibels`).
+    ///
+    /// [`GradientTableF64`]: struct.GradientTableF64.html
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn smoothed_mapped(
+        &mut self,
+        frames: usize,
+        table: &GradientTableF64,
+    ) -> SmoothOutputF64<'_> {
+        let new_normalized = self.shared_normalized.get();
+        if self.normalized != new_normalized {
+            self.normalized = new_normalized;
+
+            self.value = table.normalized_to_value(self.normalized);
+            self.smoothed.set(self.value);
+        }
+
+        self.smoothed.process(frames);
+        self.smoothed.update_status();
+
+        self.smoothed.output()
+    }
+
+    /// Create a [`GradientTableF64`] with `size` points for this parameter's range and
+    /// [`Gradient`], for use with `ParamF64::smoothed_mapped()`.
+    ///
+    /// The values in the table are already converted to the values used in DSP (see
+    /// `ParamF64::coeff()`), so the [`Unit`] conversion does not need to be calculated
+    /// when mapping.
+    ///
+    /// [`GradientTableF64`]: struct.GradientTableF64.html
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`Unit`]: enum.Unit.html
+    pub fn gradient_table(&self, size: usize) -> GradientTableF64 {
+        let mut table = GradientTableF64::new(self.min, self.max, self.gradient, size);
+        for v in table.table.iter_mut() {
+            *v = self.unit.unit_to_dsp_f64(*v);
+        }
+
+        table
+    }
+
     /// Update the sample rate (used for the parameter smoothing LPF).
     pub fn set_sample_rate(&mut self, sample_rate: SampleRate) {
         self.smoothed.set_speed(sample_rate, self.smooth_secs);
@@ -925,12 +1332,34 @@ impl ParamF64 {
     /// value for DSP. To get the latest value for DSP please use `ParamF64::smoothed()`
     /// instead.
     ///
+    /// This is in the same unit as the values given to this parameter's constructors and
+    /// `set_value()` (i.e. decibels for a parameter with `Unit::Decibels`). Use
+    /// `ParamF64::coeff()` to get the corresponding value used in DSP.
+    ///
     /// Please note that this should be called *after* calling `ParamF64::smoothed()`
     /// if you need the latest value from the corresponding [`ParamF64Handle`],
     /// otherwise this may not return the latest value.
     ///
     /// [`ParamF64Handle`]: struct.ParamF64Handle.html
     pub fn host_get_value(&self) -> f64 {
+        normalized_to_value_f64(self.normalized, self.min, self.max, self.gradient)
+    }
+
+    /// The current (un-smoothed) value of this parameter as used in DSP.
+    ///
+    /// For a parameter with `Unit::Decibels`, the values given to/returned from this
+    /// parameter's constructors, `set_value()`, and the [`ParamF64Handle`] are in decibels,
+    /// but the value used in DSP (and the values in `ParamF64::smoothed()`) is the raw
+    /// amplitude coefficient. This returns that coefficient, whereas
+    /// `ParamF64::host_get_value()` returns the value in decibels. For any other unit, this
+    /// is the same as `ParamF64::host_get_value()`.
+    ///
+    /// Please note that this should be called *after* calling `ParamF64::smoothed()`
+    /// if you need the latest value from the corresponding [`ParamF64Handle`],
+    /// otherwise this may not return the latest value.
+    ///
+    /// [`ParamF64Handle`]: struct.ParamF64Handle.html
+    pub fn coeff(&self) -> f64 {
         self.value
     }
 
@@ -940,6 +1369,25 @@ impl ParamF64 {
     pub fn shared_normalized(&self) -> Arc<AtomicF64> {
         Arc::clone(&self.shared_normalized)
     }
+
+    /// Map each normalized value in `src` (in the range `[0.0, 1.0]`) to the corresponding
+    /// (un-normalized) value, writing the results into `dst`.
+    ///
+    /// This is equivalent to calling `normalized_to_value_f64()` on each value, and is
+    /// useful for things like rendering a parameter's curve in a UI.
+    ///
+    /// Only `min(src.len(), dst.len())` values will be processed.
+    pub fn map_normalized_slice(
+        src: &[f64],
+        dst: &mut [f64],
+        min: f64,
+        max: f64,
+        gradient: Gradient,
+    ) {
+        for (d, s) in dst.iter_mut().zip(src.iter()) {
+            *d = normalized_to_value_f64(*s, min, max, gradient);
+        }
+    }
 }
 
 /// A handle to get and update the value of an auto-smoothed [`ParamF64`] from a UI.
@@ -974,6 +1422,19 @@ impl ParamF64Handle {
         )
     }
 
+    /// The value of this parameter as used in DSP.
+    ///
+    /// For a parameter with `Unit::Decibels`, `ParamF64Handle::value()` is in decibels,
+    /// while this returns the corresponding raw amplitude coefficient (the same value that
+    /// `ParamF64::coeff()` returns). For any other unit, this is the same as
+    /// `ParamF64Handle::value()`.
+    ///
+    /// Please note that this is calculated from the shared normalized value every time, so
+    /// avoid calling this every frame if you can.
+    pub fn coeff(&self) -> f64 {
+        self.unit.unit_to_dsp_f64(self.value())
+    }
+
     /// The (un-normalized) default value of the parameter.
     pub fn default_value(&self) -> f64 {
         self.default_value
@@ -989,6 +1450,12 @@ impl ParamF64Handle {
     /// Please note that this will ***NOT*** automatically notify the host of the value change
     /// if you are using this inside a plugin spec such as VST. It is intended for you use your
     /// own method for achieving this.
+    ///
+    /// The value is stored with `Ordering::Relaxed`. The corresponding [`ParamF64`] will see
+    /// the new value the next time it calls `ParamF64::smoothed()`, but no ordering is
+    /// guaranteed relative to any other writes made by this thread.
+    ///
+    /// [`ParamF64`]: struct.ParamF64.html
     pub fn set_normalized(&self, normalized: f64) {
         self.shared_normalized.set(normalized.clamp(0.0, 1.0));
     }
@@ -1076,19 +1543,7 @@ pub fn normalized_to_value_f64(normalized: f64, min: f64, max: f64, gradient: Gr
 
         Gradient::Power(exponent) => map(normalized.powf(f64::from(exponent))),
 
-        Gradient::Exponential => {
-            if normalized == 0.0 {
-                return min;
-            }
-
-            if normalized == 1.0 {
-                return max;
-            }
-
-            let minl = min.log2();
-            let range = max.log2() - minl;
-            2.0f64.powf((normalized * range) + minl)
-        }
+        Gradient::Exponential => normalized_log_to_freq_f64(normalized, min, max),
     }
 }
 
@@ -1111,11 +1566,105 @@ pub fn value_to_normalized_f64(value: f64, min: f64, max: f64, gradient: Gradien
 
         Gradient::Power(exponent) => unmap(value).powf(1.0 / f64::from(exponent)),
 
-        Gradient::Exponential => {
-            let minl = min.log2();
-            let range = max.log2() - minl;
-            (value.log2() - minl) / range
+        Gradient::Exponential => freq_to_normalized_log_f64(value, min, max),
+    }
+}
+
+/// Map the given frequency in Hz to its position in the range `[0.0, 1.0]` on a logarithmic
+/// axis spanning `[min_hz, max_hz]`.
+///
+/// This uses the same math as [`Gradient::Exponential`], so analyzers and parameters can
+/// share the same axis.
+///
+/// [`Gradient::Exponential`]: enum.Gradient.html#variant.Exponential
+pub fn freq_to_normalized_log_f64(hz: f64, min_hz: f64, max_hz: f64) -> f64 {
+    if hz <= min_hz {
+        return 0.0;
+    }
+
+    if hz >= max_hz {
+        return 1.0;
+    }
+
+    let minl = min_hz.log2();
+    let range = max_hz.log2() - minl;
+    (hz.log2() - minl) / range
+}
+
+/// Map the given position in the range `[0.0, 1.0]` on a logarithmic axis spanning
+/// `[min_hz, max_hz]` back to its frequency in Hz.
+///
+/// This is the inverse of `freq_to_normalized_log_f64()`.
+pub fn normalized_log_to_freq_f64(normalized: f64, min_hz: f64, max_hz: f64) -> f64 {
+    let normalized = normalized.clamp(0.0, 1.0);
+
+    if normalized == 0.0 {
+        return min_hz;
+    }
+
+    if normalized == 1.0 {
+        return max_hz;
+    }
+
+    let minl = min_hz.log2();
+    let range = max_hz.log2() - minl;
+    2.0f64.powf((normalized * range) + minl)
+}
+
+/// A precomputed lookup table of a [`Gradient`] mapping.
+///
+/// `Gradient::Power` and `Gradient::Exponential` call `powf()` on every conversion, which
+/// can be too slow when mapping modulation on a per-sample basis. This table samples the
+/// mapping once up front and then uses linear interpolation between the points, so no
+/// transcendental functions are called in the hot path.
+///
+/// Note that this is an approximation. The more points in the table, the more accurate
+/// the approximation will be.
+///
+/// [`Gradient`]: enum.Gradient.html
+#[derive(Debug, Clone)]
+pub struct GradientTableF64 {
+    table: Vec<f64>,
+}
+
+impl GradientTableF64 {
+    /// Create a new lookup table for the given mapping.
+    ///
+    /// * min - The minimum (de-normalized) value of the mapping.
+    /// * max - The maximum (de-normalized) value of the mapping.
+    /// * gradient - The [`Gradient`] mapping to precompute.
+    /// * size - The number of points in the table (constrained to be at least `2`).
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    pub fn new(min: f64, max: f64, gradient: Gradient, size: usize) -> Self {
+        let size = size.max(2);
+        let last = (size - 1) as f64;
+
+        let table = (0..size)
+            .map(|i| normalized_to_value_f64(i as f64 / last, min, max, gradient))
+            .collect();
+
+        Self { table }
+    }
+
+    /// Convert the given normalized value in the range `[0.0, 1.0]` into the
+    /// corresponding (approximated) value.
+    #[inline]
+    pub fn normalized_to_value(&self, normalized: f64) -> f64 {
+        let pos = normalized.clamp(0.0, 1.0) * (self.table.len() - 1) as f64;
+
+        let i = pos as usize;
+        if i >= self.table.len() - 1 {
+            return self.table[self.table.len() - 1];
         }
+
+        let fract = pos - i as f64;
+        self.table[i] + ((self.table[i + 1] - self.table[i]) * fract)
+    }
+
+    /// The number of points in this table.
+    pub fn size(&self) -> usize {
+        self.table.len()
     }
 }
 
@@ -1464,3 +2013,208 @@ impl Clone for ParamBoolHandle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freq_to_normalized_log() {
+        let geometric_mean = (20.0f32 * 20_000.0).sqrt();
+        let normalized = freq_to_normalized_log_f32(geometric_mean, 20.0, 20_000.0);
+        assert!((normalized - 0.5).abs() < 1.0e-6);
+
+        let hz = normalized_log_to_freq_f32(0.5, 20.0, 20_000.0);
+        assert!((hz - geometric_mean).abs() < 1.0e-2);
+
+        assert_eq!(
+            freq_to_normalized_log_f32(1_000.0, 20.0, 20_000.0),
+            value_to_normalized_f32(1_000.0, 20.0, 20_000.0, Gradient::Exponential)
+        );
+
+        let geometric_mean = (20.0f64 * 20_000.0).sqrt();
+        let normalized = freq_to_normalized_log_f64(geometric_mean, 20.0, 20_000.0);
+        assert!((normalized - 0.5).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_param_f32_local() {
+        let (shared_param, _handle) = ParamF32::from_value(
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+            Gradient::Linear,
+            Unit::Generic,
+            DEFAULT_SMOOTH_SECS,
+            SampleRate::default(),
+            64,
+        );
+        assert!(!shared_param.is_local());
+        assert!(shared_param.try_shared_normalized().is_some());
+        assert_eq!(shared_param.shared_normalized().get(), 0.0);
+
+        let mut param = ParamF32::from_value_local(
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+            Gradient::Linear,
+            Unit::Generic,
+            DEFAULT_SMOOTH_SECS,
+            SampleRate::default(),
+            64,
+        );
+        assert!(param.is_local());
+        assert!(param.try_shared_normalized().is_none());
+
+        param.set_value(1.0);
+        for _ in 0..1_000 {
+            let _ = param.smoothed(64);
+        }
+
+        assert_eq!(param.host_get_normalized(), 1.0);
+        assert_eq!(param.smoothed(64).values[63], 1.0);
+    }
+
+    #[test]
+    fn test_param_coeff() {
+        let (param, handle) = ParamF32::from_value(
+            -6.0,
+            0.0,
+            -90.0,
+            6.0,
+            Gradient::Linear,
+            Unit::Decibels,
+            DEFAULT_SMOOTH_SECS,
+            SampleRate::default(),
+            64,
+        );
+
+        assert!((handle.value() - -6.0).abs() < 1.0e-4);
+        assert!((param.host_get_value() - -6.0).abs() < 1.0e-4);
+        assert!((param.coeff() - 0.501).abs() < 1.0e-3);
+        assert!((handle.coeff() - 0.501).abs() < 1.0e-3);
+
+        let (param, handle) = ParamF64::from_value(
+            -6.0,
+            0.0,
+            -90.0,
+            6.0,
+            Gradient::Linear,
+            Unit::Decibels,
+            DEFAULT_SMOOTH_SECS,
+            SampleRate::default(),
+            64,
+        );
+
+        assert!((param.host_get_value() - -6.0).abs() < 1.0e-9);
+        assert!((param.coeff() - 0.501).abs() < 1.0e-3);
+        assert!((handle.coeff() - 0.501).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn test_audio_taper() {
+        let v = normalized_to_value_f32(0.5, 0.0, 1.0, Gradient::audio_taper());
+        assert!(v < 0.5);
+        assert!((v - 0.125).abs() < 1.0e-6);
+
+        let v = normalized_to_value_f32(0.125, 0.0, 1.0, Gradient::reverse_audio_taper());
+        assert!((v - 0.5).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_gradient_table() {
+        let table = GradientTableF32::new(20.0, 20_000.0, Gradient::Exponential, 256);
+
+        for i in 0..=1_000 {
+            let normalized = i as f32 / 1_000.0;
+
+            let exact = normalized_to_value_f32(normalized, 20.0, 20_000.0, Gradient::Exponential);
+            let approx = table.normalized_to_value(normalized);
+
+            assert!(((approx - exact) / exact).abs() < 1.0e-3);
+        }
+
+        assert_eq!(table.normalized_to_value(0.0), 20.0);
+        assert_eq!(table.normalized_to_value(1.0), 20_000.0);
+
+        let table = GradientTableF64::new(20.0, 20_000.0, Gradient::Exponential, 256);
+        for i in 0..=1_000 {
+            let normalized = f64::from(i) / 1_000.0;
+
+            let exact = normalized_to_value_f64(normalized, 20.0, 20_000.0, Gradient::Exponential);
+            let approx = table.normalized_to_value(normalized);
+
+            assert!(((approx - exact) / exact).abs() < 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn test_smoothed_mapped() {
+        let (mut param, handle) = ParamF32::from_value(
+            20.0,
+            20.0,
+            20.0,
+            20_000.0,
+            Gradient::Exponential,
+            Unit::Generic,
+            SecondsF64(0.0),
+            SampleRate::default(),
+            64,
+        );
+        let table = param.gradient_table(256);
+
+        handle.set_normalized(0.3);
+        let out = param.smoothed_mapped(64, &table);
+
+        let exact = normalized_to_value_f32(0.3, 20.0, 20_000.0, Gradient::Exponential);
+        assert!(((out.values[63] - exact) / exact).abs() < 1.0e-3);
+        assert_eq!(param.host_get_normalized(), 0.3);
+
+        let (mut param, handle) = ParamF64::from_value(
+            -90.0,
+            0.0,
+            -90.0,
+            6.0,
+            Gradient::Power(0.15),
+            Unit::Decibels,
+            SecondsF64(0.0),
+            SampleRate::default(),
+            64,
+        );
+        let table = param.gradient_table(1_024);
+
+        handle.set_normalized(0.7);
+        let out = param.smoothed_mapped(64, &table);
+
+        let exact = db_to_coeff_clamped_neg_90_db_f64(normalized_to_value_f64(
+            0.7,
+            -90.0,
+            6.0,
+            Gradient::Power(0.15),
+        ));
+        assert!((out.values[63] - exact).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn test_map_normalized_slice() {
+        let gradient = Gradient::Power(2.0);
+
+        let src: Vec<f32> = (0..=16).map(|i| i as f32 / 16.0).collect();
+        let mut dst = vec![0.0; src.len()];
+        ParamF32::map_normalized_slice(&src, &mut dst, -12.0, 24.0, gradient);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(*d, normalized_to_value_f32(*s, -12.0, 24.0, gradient));
+        }
+
+        let src: Vec<f64> = src.iter().map(|s| f64::from(*s)).collect();
+        let mut dst = vec![0.0; src.len()];
+        ParamF64::map_normalized_slice(&src, &mut dst, -12.0, 24.0, gradient);
+
+        for (s, d) in src.iter().zip(dst.iter()) {
+            assert_eq!(*d, normalized_to_value_f64(*s, -12.0, 24.0, gradient));
+        }
+    }
+}
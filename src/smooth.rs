@@ -27,6 +27,55 @@ impl SmoothStatus {
     }
 }
 
+/// The feedback coefficient of a one-pole low-pass filter with the given period.
+///
+/// A period of `0.0` results in a coefficient of `0.0` (no smoothing).
+pub(crate) fn one_pole_coeff_f32(sample_rate: SampleRate, seconds: SecondsF64) -> f32 {
+    (-1.0f32 / (seconds.0 as f32 * sample_rate.0 as f32)).exp()
+}
+
+/// A preset period for a low-pass parameter smoothing filter.
+///
+/// This can be passed anywhere a smoothing time in [`SecondsF64`] is accepted, such
+/// as the `smooth_secs` argument of the [`ParamF32`]/[`ParamF64`] constructors.
+///
+/// [`SecondsF64`]: ../time/struct.SecondsF64.html
+/// [`ParamF32`]: ../parameter/struct.ParamF32.html
+/// [`ParamF64`]: ../parameter/struct.ParamF64.html
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SmoothTime {
+    /// No smoothing at all (the value jumps immediately to the target value).
+    Instant,
+    /// `5 ms`
+    #[default]
+    Fast,
+    /// `20 ms`
+    Medium,
+    /// `100 ms`
+    Slow,
+    /// A custom period.
+    Custom(SecondsF64),
+}
+
+impl SmoothTime {
+    /// The period of the smoothing filter in seconds.
+    pub fn to_seconds(&self) -> SecondsF64 {
+        match self {
+            SmoothTime::Instant => SecondsF64(0.0),
+            SmoothTime::Fast => SecondsF64(5.0 / 1_000.0),
+            SmoothTime::Medium => SecondsF64(20.0 / 1_000.0),
+            SmoothTime::Slow => SecondsF64(100.0 / 1_000.0),
+            SmoothTime::Custom(seconds) => *seconds,
+        }
+    }
+}
+
+impl From<SmoothTime> for SecondsF64 {
+    fn from(s: SmoothTime) -> Self {
+        s.to_seconds()
+    }
+}
+
 pub struct SmoothOutputF32<'a> {
     pub values: &'a [f32],
     pub status: SmoothStatus,
@@ -36,6 +85,27 @@ impl<'a> SmoothOutputF32<'a> {
     pub fn is_smoothing(&self) -> bool {
         self.status.is_active()
     }
+
+    /// Copy the first `frames` smoothed values into `dst`.
+    pub fn copy_into(&self, dst: &mut [f32], frames: usize) {
+        debug_assert!(dst.len() >= frames);
+        debug_assert!(self.values.len() >= frames);
+
+        dst[..frames].copy_from_slice(&self.values[..frames]);
+    }
+
+    /// Write the first `frames` smoothed values multiplied by `master` into `dst`.
+    ///
+    /// This is useful for applying a group (VCA-style) gain without modifying the
+    /// smoother itself.
+    pub fn scaled_copy_into(&self, master: f32, dst: &mut [f32], frames: usize) {
+        debug_assert!(dst.len() >= frames);
+        debug_assert!(self.values.len() >= frames);
+
+        for (d, v) in dst[..frames].iter_mut().zip(self.values[..frames].iter()) {
+            *d = *v * master;
+        }
+    }
 }
 
 impl<'a, I> ops::Index<I> for SmoothOutputF32<'a>
@@ -141,12 +211,29 @@ impl SmoothF32 {
         self.last_output = self.output[frames - 1];
     }
 
+    /// The same as `process()`, except that the remaining values in the buffer after
+    /// `frames` are also filled with the last output value.
+    ///
+    /// This makes the whole buffer a valid continuation of the smoothed signal, which is
+    /// useful if the consumer of the buffer doesn't respect `frames`. This comes at the
+    /// cost of writing to the entire buffer every block, so prefer `process()` when
+    /// possible.
+    pub fn process_and_hold(&mut self, frames: usize) {
+        self.process(frames);
+
+        let frames = frames.min(self.output.len());
+        let last_output = self.last_output;
+        for v in self.output[frames..].iter_mut() {
+            *v = last_output;
+        }
+    }
+
     pub fn is_active(&self) -> bool {
         self.status.is_active()
     }
 
     pub fn set_speed(&mut self, sample_rate: SampleRate, seconds: SecondsF64) {
-        self.b = (-1.0f32 / (seconds.0 as f32 * sample_rate.0 as f32)).exp();
+        self.b = one_pole_coeff_f32(sample_rate, seconds);
         self.a = 1.0f32 - self.b;
     }
 
@@ -182,6 +269,27 @@ impl<'a> SmoothOutputF64<'a> {
     pub fn is_smoothing(&self) -> bool {
         self.status.is_active()
     }
+
+    /// Copy the first `frames` smoothed values into `dst`.
+    pub fn copy_into(&self, dst: &mut [f64], frames: usize) {
+        debug_assert!(dst.len() >= frames);
+        debug_assert!(self.values.len() >= frames);
+
+        dst[..frames].copy_from_slice(&self.values[..frames]);
+    }
+
+    /// Write the first `frames` smoothed values multiplied by `master` into `dst`.
+    ///
+    /// This is useful for applying a group (VCA-style) gain without modifying the
+    /// smoother itself.
+    pub fn scaled_copy_into(&self, master: f64, dst: &mut [f64], frames: usize) {
+        debug_assert!(dst.len() >= frames);
+        debug_assert!(self.values.len() >= frames);
+
+        for (d, v) in dst[..frames].iter_mut().zip(self.values[..frames].iter()) {
+            *d = *v * master;
+        }
+    }
 }
 
 impl<'a, I> ops::Index<I> for SmoothOutputF64<'a>
@@ -287,6 +395,23 @@ impl SmoothF64 {
         self.last_output = self.output[frames - 1];
     }
 
+    /// The same as `process()`, except that the remaining values in the buffer after
+    /// `frames` are also filled with the last output value.
+    ///
+    /// This makes the whole buffer a valid continuation of the smoothed signal, which is
+    /// useful if the consumer of the buffer doesn't respect `frames`. This comes at the
+    /// cost of writing to the entire buffer every block, so prefer `process()` when
+    /// possible.
+    pub fn process_and_hold(&mut self, frames: usize) {
+        self.process(frames);
+
+        let frames = frames.min(self.output.len());
+        let last_output = self.last_output;
+        for v in self.output[frames..].iter_mut() {
+            *v = last_output;
+        }
+    }
+
     pub fn is_active(&self) -> bool {
         self.status.is_active()
     }
@@ -316,3 +441,93 @@ impl fmt::Debug for SmoothF64 {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_and_hold() {
+        let mut smooth = SmoothF32::new(0.0, 64);
+        smooth.set_speed(SampleRate::default(), SecondsF64(0.005));
+
+        smooth.set(1.0);
+        smooth.process_and_hold(16);
+
+        let output = smooth.output();
+        let (last_output, _) = smooth.current_value();
+
+        assert!(output[15] > 0.0 && output[15] < 1.0);
+        assert_eq!(output[15], last_output);
+        assert!(output[16..].iter().all(|v| *v == last_output));
+
+        let mut smooth = SmoothF64::new(0.0, 64);
+        smooth.set_speed(SampleRate::default(), SecondsF64(0.005));
+
+        smooth.set(1.0);
+        smooth.process_and_hold(16);
+
+        let output = smooth.output();
+        let (last_output, _) = smooth.current_value();
+
+        assert_eq!(output[15], last_output);
+        assert!(output[16..].iter().all(|v| *v == last_output));
+    }
+
+    #[test]
+    fn test_copy_into() {
+        let mut smooth = SmoothF32::new(0.0, 64);
+        smooth.set_speed(SampleRate::default(), SecondsF64(0.005));
+        smooth.set(1.0);
+        smooth.process(32);
+
+        let mut dst = [0.0f32; 32];
+        smooth.output().copy_into(&mut dst, 32);
+
+        assert_eq!(&dst[..], &smooth.output().values[..32]);
+    }
+
+    #[test]
+    fn test_smooth_time() {
+        let sample_rate = SampleRate::new(48_000.0);
+
+        for (preset, secs) in [
+            (SmoothTime::Fast, 0.005),
+            (SmoothTime::Medium, 0.02),
+            (SmoothTime::Slow, 0.1),
+            (SmoothTime::Custom(SecondsF64(0.25)), 0.25),
+        ] {
+            let mut smooth = SmoothF32::new(0.0, 16);
+            smooth.set_speed(sample_rate, preset.into());
+
+            let b = (-1.0f32 / (secs as f32 * 48_000.0)).exp();
+            assert_eq!(smooth.b, b);
+            assert_eq!(smooth.a, 1.0 - b);
+        }
+
+        // With no smoothing the output jumps straight to the target.
+        let mut smooth = SmoothF32::new(0.0, 16);
+        smooth.set_speed(sample_rate, SmoothTime::Instant.into());
+        assert_eq!(smooth.b, 0.0);
+        assert_eq!(smooth.a, 1.0);
+
+        smooth.set(1.0);
+        smooth.process(4);
+        assert!(smooth.output().values[..4].iter().all(|v| *v == 1.0));
+    }
+
+    #[test]
+    fn test_scaled_copy_into() {
+        let mut smooth = SmoothF32::new(0.0, 64);
+        smooth.set_speed(SampleRate::default(), SecondsF64(0.005));
+        smooth.set(1.0);
+        smooth.process(32);
+
+        let mut dst = [0.0f32; 32];
+        smooth.output().scaled_copy_into(0.5, &mut dst, 32);
+
+        for (d, v) in dst.iter().zip(smooth.output().values[..32].iter()) {
+            assert_eq!(*d, *v * 0.5);
+        }
+    }
+}
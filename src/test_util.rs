@@ -0,0 +1,96 @@
+//! Helpers for writing tests against the (lossy) time conversions in this crate.
+//!
+//! This module is only available with the `test-util` feature enabled.
+
+use crate::time::{FrameTime, MusicalTime, SecondsF64};
+
+/// Assert that two [`SecondsF64`] values are within `eps` seconds of each other.
+///
+/// [`SecondsF64`]: ../time/struct.SecondsF64.html
+#[track_caller]
+pub fn assert_seconds_near(left: SecondsF64, right: SecondsF64, eps: f64) {
+    let diff = (left.0 - right.0).abs();
+
+    if diff.is_nan() || diff > eps {
+        panic!(
+            "assertion failed: `(left ≈ right)` in seconds\n  left: `{:?}`\n right: `{:?}`\n  diff: `{}`\n   eps: `{}`",
+            left, right, diff, eps
+        );
+    }
+}
+
+/// Assert that two [`FrameTime`] values are equal.
+///
+/// [`FrameTime`]: ../time/struct.FrameTime.html
+#[track_caller]
+pub fn assert_frames_eq(left: FrameTime, right: FrameTime) {
+    if left != right {
+        panic!(
+            "assertion failed: `(left == right)` in frames\n  left: `{}`\n right: `{}`\n  diff: `{}`",
+            left.0,
+            right.0,
+            i128::from(left.0) - i128::from(right.0)
+        );
+    }
+}
+
+/// Assert that two [`MusicalTime`] values are within `eps_ticks` ticks of each other.
+///
+/// [`MusicalTime`]: ../time/struct.MusicalTime.html
+#[track_caller]
+pub fn assert_musical_near(left: MusicalTime, right: MusicalTime, eps_ticks: u64) {
+    let diff = if left > right {
+        left.total_ticks() - right.total_ticks()
+    } else {
+        right.total_ticks() - left.total_ticks()
+    };
+
+    if diff > eps_ticks {
+        panic!(
+            "assertion failed: `(left ≈ right)` in musical time\n  left: `{:?}` ({} beats)\n right: `{:?}` ({} beats)\n  diff: `{}` ticks\n   eps: `{}` ticks",
+            left,
+            left.as_beats_f64(),
+            right,
+            right.as_beats_f64(),
+            diff,
+            eps_ticks
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helpers_pass() {
+        assert_seconds_near(SecondsF64(1.0), SecondsF64(1.0 + 1.0e-10), 1.0e-9);
+        assert_frames_eq(FrameTime(5), FrameTime(5));
+        assert_musical_near(MusicalTime::new(1, 10), MusicalTime::new(1, 12), 2);
+        assert_musical_near(MusicalTime::new(1, 12), MusicalTime::new(1, 10), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "in seconds")]
+    fn test_seconds_near_fails() {
+        assert_seconds_near(SecondsF64(1.0), SecondsF64(1.1), 1.0e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "in seconds")]
+    fn test_seconds_near_fails_on_nan() {
+        assert_seconds_near(SecondsF64(f64::NAN), SecondsF64(1.0), 1.0e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "in frames")]
+    fn test_frames_eq_fails() {
+        assert_frames_eq(FrameTime(5), FrameTime(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "in musical time")]
+    fn test_musical_near_fails() {
+        assert_musical_near(MusicalTime::new(1, 10), MusicalTime::new(1, 13), 2);
+    }
+}
@@ -0,0 +1,72 @@
+//! Conversions between [`SecondsF64`] and [`std::time::Duration`].
+//!
+//! | From \ To    | `SecondsF64`         | `Duration`                                       |
+//! |--------------|----------------------|--------------------------------------------------|
+//! | `SecondsF64` | -                    | `SecondsF64::to_duration()`, `Duration::from()`  |
+//! | `Duration`   | `SecondsF64::from()` | -                                                |
+//!
+//! Converting from a `Duration` never fails, but it is *NOT* lossless for very long
+//! durations since an `f64` cannot represent every nanosecond value.
+//!
+//! Converting to a `Duration` saturates: negative and `NaN` values become
+//! `Duration::ZERO`, and values too large to be represented become `Duration::MAX`.
+//!
+//! [`SecondsF64`]: ../struct.SecondsF64.html
+
+use std::time::Duration;
+
+use super::SecondsF64;
+
+impl SecondsF64 {
+    /// Convert to a [`Duration`].
+    ///
+    /// Note that this conversion is *NOT* lossless.
+    ///
+    /// If the seconds value is negative or `NaN`, then `Duration::ZERO` will be returned
+    /// instead. If the seconds value is too large to fit into a `Duration`, then
+    /// `Duration::MAX` will be returned instead.
+    ///
+    /// See the [`time::duration`] module for a summary of all the conversions.
+    ///
+    /// [`time::duration`]: duration/index.html
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn to_duration(&self) -> Duration {
+        if self.0 > 0.0 {
+            Duration::try_from_secs_f64(self.0).unwrap_or(Duration::MAX)
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+impl From<Duration> for SecondsF64 {
+    fn from(d: Duration) -> Self {
+        SecondsF64(d.as_secs_f64())
+    }
+}
+
+impl From<SecondsF64> for Duration {
+    fn from(s: SecondsF64) -> Self {
+        s.to_duration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_round_trip() {
+        let d = Duration::from_millis(2_500);
+        let s = SecondsF64::from(d);
+
+        assert_eq!(s, SecondsF64(2.5));
+        assert_eq!(s.to_duration(), d);
+        assert_eq!(Duration::from(s), d);
+
+        assert_eq!(SecondsF64(-1.0).to_duration(), Duration::ZERO);
+        assert_eq!(SecondsF64(f64::NAN).to_duration(), Duration::ZERO);
+        assert_eq!(SecondsF64(f64::INFINITY).to_duration(), Duration::MAX);
+        assert_eq!(SecondsF64(f64::MAX).to_duration(), Duration::MAX);
+    }
+}
@@ -1,10 +1,10 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
-use super::{MusicalTime, SampleRate, SecondsF64, SuperclockTime};
+use super::{MusicalTime, SampleRate, SecondsF64, SuperclockTime, SUPER_SAMPLE_TICKS_PER_SECOND};
 
 /// Unit of time length in frames (samples in a single audio channel).
 #[cfg_attr(feature = "serde-derive", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct FrameTime(pub u64);
 
 impl FrameTime {
@@ -12,6 +12,36 @@ impl FrameTime {
         Self(frame)
     }
 
+    /// Round up to the nearest multiple of `block_size`.
+    ///
+    /// This is useful for aligning an event to the start of the next processing block.
+    ///
+    /// If `block_size` is `0`, then this will return `self` unchanged. If the result would
+    /// overflow, then `FrameTime(u64::MAX)` will be returned instead.
+    pub fn align_up(&self, block_size: u64) -> FrameTime {
+        if block_size == 0 {
+            return *self;
+        }
+
+        let rem = self.0 % block_size;
+        if rem == 0 {
+            *self
+        } else {
+            FrameTime((self.0 - rem).saturating_add(block_size))
+        }
+    }
+
+    /// Round down to the nearest multiple of `block_size`.
+    ///
+    /// If `block_size` is `0`, then this will return `self` unchanged.
+    pub fn align_down(&self, block_size: u64) -> FrameTime {
+        if block_size == 0 {
+            return *self;
+        }
+
+        FrameTime(self.0 - (self.0 % block_size))
+    }
+
     /// Convert to the corresponding time in [`SecondsF64`] with the given [`SampleRate`].
     ///
     /// Note that this conversion is *NOT* lossless.
@@ -33,6 +63,20 @@ impl FrameTime {
         self.to_seconds_f64(sample_rate).to_musical(bpm)
     }
 
+    /// Convert to the corresponding fractional position in musical beats.
+    ///
+    /// Unlike `FrameTime::to_musical()`, the result is not rounded to the nearest
+    /// [`MusicalTime`] tick. This is useful for analysis where the exact fractional
+    /// beat position is needed (see also `MusicalTime::as_beats_f64()`).
+    ///
+    /// Note that this must be re-calculated after recieving a new [`SampleRate`].
+    ///
+    /// [`MusicalTime`]: struct.MusicalTime.html
+    /// [`SampleRate`]: struct.SampleRate.html
+    pub fn to_beats_f64(&self, bpm: f64, sample_rate: SampleRate) -> f64 {
+        self.to_seconds_f64(sample_rate).0 * (bpm / 60.0)
+    }
+
     /// Convert to the corresponding time length in [`SuperclockTime`] from the given [`SampleRate`].
     ///
     /// This conversion **IS** lossless if the sample rate happens to be equal to one of the common
@@ -44,6 +88,103 @@ impl FrameTime {
     pub fn to_super_frame(&self, sample_rate: SampleRate) -> SuperclockTime {
         SuperclockTime::from_frame(*self, sample_rate)
     }
+
+    /// Convert to the corresponding time length in [`SuperclockTime`] from the given
+    /// [`SampleRate`], rounded to the nearest tick.
+    ///
+    /// Unlike `FrameTime::to_super_frame()`, this uses 128 bit integer math instead of
+    /// falling back to `f64` for uncommon sample rates, so there is no loss of precision for
+    /// very large frame values. The result is exact (lossless) for the common sample rates,
+    /// and otherwise is within half a tick of the exact value.
+    ///
+    /// Note that the sample rate is rounded to the nearest integer before converting. If it
+    /// rounds to `0`, then a sample rate of `1` is used instead.
+    ///
+    /// If the result is greater than `u32::MAX` seconds (e.g. `u64::MAX` frames at 48 kHz),
+    /// then it will be constrained to
+    /// `SuperclockTime::new(u32::MAX, SUPER_SAMPLE_TICKS_PER_SECOND - 1)`.
+    ///
+    /// [`SuperclockTime`]: struct.SuperclockTime.html
+    /// [`SampleRate`]: struct.SampleRate.html
+    pub fn to_super_frame_exact_round(&self, sample_rate: SampleRate) -> SuperclockTime {
+        let rate = u128::from(sample_rate.as_u32().max(1));
+        let tps = u128::from(SUPER_SAMPLE_TICKS_PER_SECOND);
+
+        let total_ticks = ((u128::from(self.0) * tps) + (rate / 2)) / rate;
+
+        let seconds = total_ticks / tps;
+        if seconds > u128::from(u32::MAX) {
+            SuperclockTime::new(u32::MAX, SUPER_SAMPLE_TICKS_PER_SECOND - 1)
+        } else {
+            SuperclockTime::new(seconds as u32, (total_ticks % tps) as u32)
+        }
+    }
+
+    /// Add `rhs` to self, wrapping around at the boundary of the underlying `u64`.
+    ///
+    /// Unlike the `+` operator (which panics on overflow in debug builds), this is
+    /// intended for cyclic counters where wrapping around is the desired behavior.
+    pub fn wrapping_add(self, rhs: FrameTime) -> FrameTime {
+        FrameTime(self.0.wrapping_add(rhs.0))
+    }
+
+    /// Subtract `rhs` from self, wrapping around at the boundary of the underlying `u64`.
+    ///
+    /// Unlike the `-` operator (which panics on overflow in debug builds), this is
+    /// intended for cyclic counters where wrapping around is the desired behavior.
+    pub fn wrapping_sub(self, rhs: FrameTime) -> FrameTime {
+        FrameTime(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+/// Find where a clip starting at `clip_timeline_start` on the timeline lands inside the
+/// processing block `[block_start, block_start + block_len)`.
+///
+/// This returns `(dst_offset_in_block, src_frame)`, where `dst_offset_in_block` is the
+/// frame inside the block where the clip starts playing, and `src_frame` is the frame
+/// inside the clip to start reading from.
+///
+/// This returns `None` if the clip starts after the end of the block (or if `block_len`
+/// is `0`). Note that the length of the clip is not taken into account.
+pub fn clip_block_mapping(
+    clip_timeline_start: FrameTime,
+    block_start: FrameTime,
+    block_len: FrameTime,
+) -> Option<(usize, FrameTime)> {
+    if block_len.0 == 0 {
+        return None;
+    }
+    if let Some(block_end) = block_start.0.checked_add(block_len.0) {
+        if clip_timeline_start.0 >= block_end {
+            return None;
+        }
+    }
+
+    if clip_timeline_start >= block_start {
+        Some(((clip_timeline_start - block_start).0 as usize, FrameTime(0)))
+    } else {
+        Some((0, block_start - clip_timeline_start))
+    }
+}
+
+/// Get the position of `pos` within the loop `[loop_start, loop_end)` as a normalized
+/// phase in the range `[0.0, 1.0)`.
+///
+/// If `pos` lies outside of the loop, then it will be wrapped into the loop as if the
+/// loop extended infinitely in both directions.
+///
+/// If the loop has a length of zero (or `loop_end` is before `loop_start`), then `0.0`
+/// will be returned.
+pub fn loop_phase(pos: FrameTime, loop_start: FrameTime, loop_end: FrameTime) -> f32 {
+    if loop_end <= loop_start {
+        return 0.0;
+    }
+
+    let len = i128::from(loop_end.0 - loop_start.0);
+    let offset = (i128::from(pos.0) - i128::from(loop_start.0)).rem_euclid(len);
+
+    // Make sure that rounding to `f32` never produces exactly `1.0`.
+    ((offset as f64 / len as f64) as f32).min(1.0 - f32::EPSILON)
 }
 
 impl Default for FrameTime {
@@ -112,3 +253,140 @@ impl MulAssign<u64> for FrameTime {
         *self = *self * other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_beats_f64() {
+        let sample_rate = SampleRate::new(48_000.0);
+
+        assert_eq!(FrameTime(36_000).to_beats_f64(120.0, sample_rate), 1.5);
+        assert!(
+            (FrameTime(10_000).to_beats_f64(120.0, sample_rate) - (10.0 / 24.0)).abs() < 1.0e-12
+        );
+    }
+
+    #[test]
+    fn test_clip_block_mapping() {
+        let block_start = FrameTime(256);
+        let block_len = FrameTime(128);
+
+        // Clip starts before the block.
+        assert_eq!(
+            clip_block_mapping(FrameTime(200), block_start, block_len),
+            Some((0, FrameTime(56)))
+        );
+
+        // Clip starts in the middle of the block.
+        assert_eq!(
+            clip_block_mapping(FrameTime(300), block_start, block_len),
+            Some((44, FrameTime(0)))
+        );
+        assert_eq!(
+            clip_block_mapping(FrameTime(256), block_start, block_len),
+            Some((0, FrameTime(0)))
+        );
+        assert_eq!(
+            clip_block_mapping(FrameTime(383), block_start, block_len),
+            Some((127, FrameTime(0)))
+        );
+
+        // Clip starts after the block.
+        assert_eq!(
+            clip_block_mapping(FrameTime(384), block_start, block_len),
+            None
+        );
+        assert_eq!(
+            clip_block_mapping(FrameTime(200), block_start, FrameTime(0)),
+            None
+        );
+
+        // The end of the block is past `u64::MAX`.
+        let block_start = FrameTime(u64::MAX - 10);
+        assert_eq!(
+            clip_block_mapping(FrameTime(5), block_start, FrameTime(64)),
+            Some((0, FrameTime(u64::MAX - 15)))
+        );
+        assert_eq!(
+            clip_block_mapping(FrameTime(u64::MAX), block_start, FrameTime(64)),
+            Some((10, FrameTime(0)))
+        );
+    }
+
+    #[test]
+    fn test_to_super_frame_exact_round() {
+        let sample_rate = SampleRate::new(47_999.0);
+
+        let frame = FrameTime((47_999 * 10_000_000) + 1);
+        let exact = frame.to_super_frame_exact_round(sample_rate);
+
+        // 282,240,000 / 47,999 = 5,880.12...
+        assert_eq!(exact, SuperclockTime::new(10_000_000, 5_880));
+
+        // The f64 path is close, but cannot be relied upon to be exact at this magnitude.
+        let approx = frame.to_super_frame(sample_rate);
+        let diff = (approx.total_ticks() as i64 - exact.total_ticks() as i64).abs();
+        assert!(diff <= 4);
+
+        // Common sample rates match the lossless conversion.
+        let frame = FrameTime(123_456_789);
+        assert_eq!(
+            frame.to_super_frame_exact_round(SampleRate::new(44_100.0)),
+            frame.to_super_frame(SampleRate::new(44_100.0))
+        );
+
+        // Results past `u32::MAX` seconds saturate.
+        assert_eq!(
+            FrameTime(u64::MAX).to_super_frame_exact_round(SampleRate::new(48_000.0)),
+            SuperclockTime::new(u32::MAX, SUPER_SAMPLE_TICKS_PER_SECOND - 1)
+        );
+
+        // A sample rate that rounds to zero is treated as 1 Hz.
+        assert_eq!(
+            FrameTime(3).to_super_frame_exact_round(SampleRate::new(0.25)),
+            SuperclockTime::new(3, 0)
+        );
+    }
+
+    #[test]
+    fn test_align() {
+        assert_eq!(FrameTime(130).align_up(64), FrameTime(192));
+        assert_eq!(FrameTime(130).align_down(64), FrameTime(128));
+
+        assert_eq!(FrameTime(128).align_up(64), FrameTime(128));
+        assert_eq!(FrameTime(128).align_down(64), FrameTime(128));
+
+        assert_eq!(FrameTime(130).align_up(0), FrameTime(130));
+        assert_eq!(FrameTime(130).align_down(0), FrameTime(130));
+
+        assert_eq!(FrameTime(u64::MAX - 1).align_up(64), FrameTime(u64::MAX));
+    }
+
+    #[test]
+    fn test_loop_phase() {
+        let start = FrameTime(1_000);
+        let end = FrameTime(2_000);
+
+        assert_eq!(loop_phase(FrameTime(1_000), start, end), 0.0);
+        assert_eq!(loop_phase(FrameTime(1_500), start, end), 0.5);
+        assert_eq!(loop_phase(FrameTime(1_999), start, end), 0.999);
+
+        // Positions outside the loop wrap around.
+        assert_eq!(loop_phase(FrameTime(2_250), start, end), 0.25);
+        assert_eq!(loop_phase(FrameTime(750), start, end), 0.75);
+
+        // A very long loop never reaches `1.0`.
+        assert!(loop_phase(FrameTime(u64::MAX - 1), FrameTime(0), FrameTime(u64::MAX)) < 1.0);
+
+        assert_eq!(loop_phase(FrameTime(1_500), start, start), 0.0);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(FrameTime(1).wrapping_sub(FrameTime(2)), FrameTime(u64::MAX));
+        assert_eq!(FrameTime(u64::MAX).wrapping_add(FrameTime(3)), FrameTime(2));
+        assert_eq!(FrameTime(5).wrapping_sub(FrameTime(2)), FrameTime(3));
+    }
+}
@@ -3,6 +3,7 @@
 #[cfg(feature = "serde-derive")]
 use serde::{Deserialize, Serialize};
 
+pub mod duration;
 mod frame_time;
 mod musical_time;
 mod sample_rate;
@@ -10,13 +11,80 @@ mod seconds;
 mod superclock_time;
 //mod video_timecode;
 
-pub use frame_time::FrameTime;
-pub use musical_time::{MusicalTime, SUPER_BEAT_TICKS_PER_BEAT};
+pub use frame_time::{clip_block_mapping, loop_phase, FrameTime};
+pub use musical_time::{
+    groove_quantize, lfo_phase_to_musical, next_beat_offset_in_block, super_beats_per_frame,
+    super_beats_per_frame_q32, MusicalTime, MusicalToFrame, SUPER_BEAT_TICKS_PER_BEAT,
+};
 pub use sample_rate::SampleRate;
 pub use seconds::SecondsF64;
-pub use superclock_time::{SuperclockTime, SUPER_SAMPLE_TICKS_PER_SECOND};
+pub use superclock_time::{
+    super_sample_ticks_per_sample, SuperclockTime, COMMON_SAMPLE_RATES,
+    SUPER_SAMPLE_TICKS_PER_SECOND,
+};
 //pub use video_timecode::{VideoFpsFormat, VideoTimecode};
 
+/// A time type that can be converted to [`SecondsF64`] given some context.
+///
+/// This allows generic timeline code to get the time in seconds regardless of which time
+/// type it is dealing with.
+///
+/// | Type               | `Context`      |
+/// |--------------------|----------------|
+/// | [`FrameTime`]      | [`SampleRate`] |
+/// | [`MusicalTime`]    | `f64` (bpm)    |
+/// | [`SuperclockTime`] | `()`           |
+/// | [`SecondsF64`]     | `()`           |
+///
+/// Note that these conversions are *NOT* lossless.
+///
+/// [`SecondsF64`]: struct.SecondsF64.html
+/// [`FrameTime`]: struct.FrameTime.html
+/// [`SampleRate`]: struct.SampleRate.html
+/// [`MusicalTime`]: struct.MusicalTime.html
+/// [`SuperclockTime`]: struct.SuperclockTime.html
+pub trait ToSeconds {
+    /// The extra information needed to perform the conversion.
+    type Context;
+
+    /// Convert to the corresponding time in [`SecondsF64`].
+    ///
+    /// [`SecondsF64`]: struct.SecondsF64.html
+    fn to_seconds(&self, ctx: Self::Context) -> SecondsF64;
+}
+
+impl ToSeconds for FrameTime {
+    type Context = SampleRate;
+
+    fn to_seconds(&self, sample_rate: SampleRate) -> SecondsF64 {
+        self.to_seconds_f64(sample_rate)
+    }
+}
+
+impl ToSeconds for MusicalTime {
+    type Context = f64;
+
+    fn to_seconds(&self, bpm: f64) -> SecondsF64 {
+        self.to_seconds_f64(bpm)
+    }
+}
+
+impl ToSeconds for SuperclockTime {
+    type Context = ();
+
+    fn to_seconds(&self, _: ()) -> SecondsF64 {
+        self.to_seconds_f64()
+    }
+}
+
+impl ToSeconds for SecondsF64 {
+    type Context = ();
+
+    fn to_seconds(&self, _: ()) -> SecondsF64 {
+        *self
+    }
+}
+
 /// A reliable timestamp for events on the timeline.
 #[cfg_attr(feature = "serde-derive", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -41,3 +109,41 @@ pub enum Timestamp {
     // TODO: Flesh this out once I have a better idea how this should work.
     // Video(VideoTimecode),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total_seconds<T: ToSeconds>(times: &[T], ctx: T::Context) -> SecondsF64
+    where
+        T::Context: Copy,
+    {
+        times
+            .iter()
+            .fold(SecondsF64(0.0), |acc, t| acc + t.to_seconds(ctx))
+    }
+
+    #[test]
+    fn test_to_seconds() {
+        let frames = [FrameTime(24_000), FrameTime(48_000)];
+        assert_eq!(
+            total_seconds(&frames, SampleRate::new(48_000.0)),
+            SecondsF64(1.5)
+        );
+
+        let musical = [
+            MusicalTime::from_beats(1),
+            MusicalTime::from_half_beats(0, 1),
+        ];
+        assert_eq!(total_seconds(&musical, 120.0), SecondsF64(0.75));
+
+        let superclock = [
+            SuperclockTime::from_seconds(2),
+            SuperclockTime::from_seconds(3),
+        ];
+        assert_eq!(total_seconds(&superclock, ()), SecondsF64(5.0));
+
+        let seconds = [SecondsF64(0.25), SecondsF64(0.5)];
+        assert_eq!(total_seconds(&seconds, ()), SecondsF64(0.75));
+    }
+}
@@ -77,6 +77,50 @@ impl MusicalTime {
         (u64::from(self.beats) * u64::from(SUPER_BEAT_TICKS_PER_BEAT)) + u64::from(self.ticks)
     }
 
+    /// The canonical ordering value of this musical time.
+    ///
+    /// This is currently the same as `MusicalTime::total_ticks()`, and sorting by this key
+    /// is equivalent to sorting by `MusicalTime`'s `Ord` implementation. It is useful as a
+    /// key in event queues that store `(MusicalTime, payload)` pairs.
+    ///
+    /// ```
+    /// use meadowlark_core_types::time::MusicalTime;
+    ///
+    /// let mut events = vec![
+    ///     (MusicalTime::from_half_beats(1, 1), "c"),
+    ///     (MusicalTime::from_beats(0), "a"),
+    ///     (MusicalTime::from_quarter_beats(1, 1), "b"),
+    /// ];
+    ///
+    /// events.sort_by_key(|(time, _)| time.sort_key());
+    ///
+    /// assert_eq!(events.iter().map(|(_, e)| *e).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    /// ```
+    pub fn sort_key(&self) -> u64 {
+        self.total_ticks()
+    }
+
+    /// Get the musical time from the total number of ticks.
+    ///
+    /// If the number of beats does not fit in a `u32`, then the number of beats will be
+    /// constrained to `u32::MAX`.
+    pub fn from_total_ticks(total_ticks: u64) -> Self {
+        let tpb = u64::from(SUPER_BEAT_TICKS_PER_BEAT);
+        let beats = total_ticks / tpb;
+
+        if beats > u64::from(u32::MAX) {
+            Self {
+                beats: u32::MAX,
+                ticks: SUPER_BEAT_TICKS_PER_BEAT - 1,
+            }
+        } else {
+            Self {
+                beats: beats as u32,
+                ticks: (total_ticks % tpb) as u32,
+            }
+        }
+    }
+
     /// * `beats` - The time in musical beats.
     pub fn from_beats(beats: u32) -> Self {
         Self { beats, ticks: 0 }
@@ -547,6 +591,26 @@ impl MusicalTime {
         self.num_fractional_beats::<24>()
     }
 
+    /// Find the coarsest grid (the smallest number of even divisions of a beat) where this
+    /// time lands exactly on the grid.
+    ///
+    /// For example, a time `3/16` of a beat after the start of a beat will return `16`,
+    /// and a time `2/3` of a beat after the start of a beat will return `3`. A time that
+    /// lands exactly on a beat will return `1`. This is useful for choosing which note value
+    /// to use when displaying this time in notation.
+    ///
+    /// This returns `None` if that number of divisions is greater than `max_divisor`.
+    pub fn coarsest_exact_division(&self, max_divisor: u64) -> Option<u64> {
+        let tpb = u64::from(SUPER_BEAT_TICKS_PER_BEAT);
+        let divisor = tpb / gcd(u64::from(self.ticks), tpb);
+
+        if divisor <= max_divisor {
+            Some(divisor)
+        } else {
+            None
+        }
+    }
+
     /// Convert to the corresponding time in [`SecondsF64`].
     ///
     /// Note that this conversion is *NOT* lossless.
@@ -601,6 +665,27 @@ impl MusicalTime {
         self.to_seconds_f64(bpm).to_sub_frame(sample_rate)
     }
 
+    /// Convert to the corresponding discrete [`FrameTime`] floored to the nearest frame,
+    /// while also returning the fractional sub-sample part, using a precomputed
+    /// [`MusicalToFrame`] factor.
+    ///
+    /// This is the same as `MusicalTime::to_sub_frame()`, but it avoids recalculating the
+    /// conversion factor on every call. Because the factor is applied in a different order,
+    /// the results may differ very slightly from `MusicalTime::to_sub_frame()`.
+    ///
+    /// Note that this conversion is *NOT* lossless.
+    ///
+    /// [`FrameTime`]: struct.FrameTime.html
+    /// [`MusicalToFrame`]: struct.MusicalToFrame.html
+    pub fn to_sub_frame_cached(&self, factor: &MusicalToFrame) -> (FrameTime, f64) {
+        let frames = self.as_beats_f64() * factor.factor();
+        if frames > 0.0 {
+            (FrameTime(frames.floor() as u64), frames.fract())
+        } else {
+            (FrameTime(0), 0.0)
+        }
+    }
+
     /// Convert to the corresponding discrete [`SuperclockTime`]. This will be rounded to the nearest super-frame.
     ///
     /// Note that this conversion is *NOT* lossless.
@@ -668,6 +753,243 @@ impl MusicalTime {
     }
 }
 
+/// A cached conversion factor from [`MusicalTime`] to [`FrameTime`] for a fixed bpm and
+/// [`SampleRate`].
+///
+/// This precomputes the combined `60.0 / bpm * sample_rate` multiplier once, so converting
+/// many times per block only costs a single multiplication each.
+///
+/// Note that this conversion is *NOT* lossless. Because the multiplications are performed
+/// in a different order, the result may differ by one frame from the equivalent method on
+/// [`MusicalTime`] when the exact value lands right on a rounding boundary.
+///
+/// Note that this must be re-created after recieving a new bpm or [`SampleRate`].
+///
+/// [`MusicalTime`]: struct.MusicalTime.html
+/// [`FrameTime`]: struct.FrameTime.html
+/// [`SampleRate`]: struct.SampleRate.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MusicalToFrame {
+    factor: f64,
+}
+
+impl MusicalToFrame {
+    pub fn new(bpm: f64, sample_rate: SampleRate) -> Self {
+        Self {
+            factor: 60.0 / bpm * sample_rate,
+        }
+    }
+
+    /// The number of frames in a single beat.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Convert to the corresponding discrete [`FrameTime`]. This will be rounded to the nearest frame.
+    ///
+    /// [`FrameTime`]: struct.FrameTime.html
+    pub fn convert_round(&self, time: MusicalTime) -> FrameTime {
+        let frames = time.as_beats_f64() * self.factor;
+        if frames > 0.0 {
+            FrameTime(frames.round() as u64)
+        } else {
+            FrameTime(0)
+        }
+    }
+
+    /// Convert to the corresponding discrete [`FrameTime`]. This will be floored to the nearest frame.
+    ///
+    /// [`FrameTime`]: struct.FrameTime.html
+    pub fn convert_floor(&self, time: MusicalTime) -> FrameTime {
+        let frames = time.as_beats_f64() * self.factor;
+        if frames > 0.0 {
+            FrameTime(frames.floor() as u64)
+        } else {
+            FrameTime(0)
+        }
+    }
+
+    /// Convert to the corresponding discrete [`FrameTime`]. This will be ceil-ed to the nearest frame.
+    ///
+    /// [`FrameTime`]: struct.FrameTime.html
+    pub fn convert_ceil(&self, time: MusicalTime) -> FrameTime {
+        let frames = time.as_beats_f64() * self.factor;
+        if frames > 0.0 {
+            FrameTime(frames.ceil() as u64)
+        } else {
+            FrameTime(0)
+        }
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// The number of super-beat ticks (`1 / SUPER_BEAT_TICKS_PER_BEAT` of a beat) that elapse
+/// in a single frame at the given bpm and [`SampleRate`].
+///
+/// [`SampleRate`]: struct.SampleRate.html
+pub fn super_beats_per_frame(bpm: f64, sample_rate: SampleRate) -> f64 {
+    (bpm / 60.0) * f64::from(SUPER_BEAT_TICKS_PER_BEAT) / sample_rate.0
+}
+
+/// The same as `super_beats_per_frame()`, but as an unsigned fixed-point number with 32
+/// fractional bits (rounded to the nearest representable value).
+///
+/// This can be used to advance a playhead once per frame without accumulating any
+/// floating-point error along the way. Note that a `u64` accumulator in this format can
+/// only hold `2^32` ticks (about 3.46 beats), so it must either hold only the fractional
+/// part of the position, carrying the whole ticks into the [`MusicalTime`] each frame:
+///
+/// ```
+/// # use meadowlark_core_types::time::*;
+/// let incr = super_beats_per_frame_q32(120.0, SampleRate::new(48_000.0));
+///
+/// let mut time = MusicalTime::new(0, 0);
+/// let mut frac: u64 = 0;
+/// for _ in 0..48_000 {
+///     frac += incr;
+///     time = MusicalTime::from_total_ticks(time.total_ticks() + (frac >> 32));
+///     frac &= 0xFFFF_FFFF;
+/// }
+///
+/// assert_eq!(time, MusicalTime::new(2, 0));
+/// ```
+///
+/// or it must be a `u128`, in which case the position after `n` frames can also be
+/// computed directly:
+///
+/// ```
+/// # use meadowlark_core_types::time::*;
+/// let incr = super_beats_per_frame_q32(120.0, SampleRate::new(48_000.0));
+/// let start = MusicalTime::new(1, 0);
+/// let n: u128 = 48_000;
+///
+/// let ticks = ((u128::from(start.total_ticks()) << 32) + n * u128::from(incr)) >> 32;
+/// assert_eq!(MusicalTime::from_total_ticks(ticks as u64), MusicalTime::new(3, 0));
+/// ```
+///
+/// If the value does not fit in a `u64` (or is negative or `NaN`), then it will be
+/// constrained to the range `[0, u64::MAX]`.
+///
+/// [`MusicalTime`]: struct.MusicalTime.html
+pub fn super_beats_per_frame_q32(bpm: f64, sample_rate: SampleRate) -> u64 {
+    (super_beats_per_frame(bpm, sample_rate) * 4_294_967_296.0).round() as u64
+}
+
+/// Find the offset (in frames) of the next whole beat within a block.
+///
+/// * `pos` - The musical time at the first frame of the block.
+/// * `incr_q32` - The number of super-beat ticks per frame as an unsigned fixed-point
+/// number with 32 fractional bits (see `super_beats_per_frame_q32()`).
+/// * `block_frames` - The number of frames in the block.
+///
+/// This uses only integer math, so it gives the same result as advancing `pos` by
+/// `incr_q32` once per frame (starting with no fractional part), no matter how long the
+/// session has been running. If `pos` lies exactly on a beat, then `Some(0)` is returned.
+///
+/// Returns `None` if the next beat does not occur within the block.
+pub fn next_beat_offset_in_block(
+    pos: MusicalTime,
+    incr_q32: u64,
+    block_frames: usize,
+) -> Option<usize> {
+    let next_beat = if pos.ticks == 0 {
+        u64::from(pos.beats)
+    } else {
+        u64::from(pos.beats) + 1
+    };
+    let remaining_ticks = next_beat * u64::from(SUPER_BEAT_TICKS_PER_BEAT) - pos.total_ticks();
+
+    if remaining_ticks == 0 {
+        return if block_frames > 0 { Some(0) } else { None };
+    }
+    if incr_q32 == 0 {
+        return None;
+    }
+
+    let remaining_q32 = u128::from(remaining_ticks) << 32;
+    let incr_q32 = u128::from(incr_q32);
+    let offset = remaining_q32.div_ceil(incr_q32);
+
+    if offset < block_frames as u128 {
+        Some(offset as usize)
+    } else {
+        None
+    }
+}
+
+/// Convert the normalized phase of a tempo-synced LFO into the corresponding offset
+/// within one `period` of the LFO.
+///
+/// `phase` will be constrained to the range `[0.0, 1.0)` (a `NaN` phase is treated as
+/// `0.0`). The result is floored to the nearest tick, so it is always less than `period`
+/// (unless `period` is zero).
+pub fn lfo_phase_to_musical(phase: f32, period: MusicalTime) -> MusicalTime {
+    let phase = if phase.is_nan() {
+        0.0
+    } else {
+        f64::from(phase).clamp(0.0, 1.0 - f64::EPSILON)
+    };
+
+    MusicalTime::from_total_ticks((period.total_ticks() as f64 * phase).floor() as u64)
+}
+
+/// Quantize each of the given times toward the nearest slot of a groove template.
+///
+/// * `times` - The times to quantize (in place).
+/// * `template` - The offsets of the slots in the groove template, relative to the start of
+/// each period of `beat_len`. Offsets larger than `beat_len` are wrapped.
+/// * `beat_len` - The length of one period of the groove template.
+/// * `strength` - How far to move each time toward its nearest slot, where `0.0` leaves the
+/// times untouched and `1.0` snaps them exactly onto the slot. This will be constrained to
+/// the range `[0.0, 1.0]`.
+///
+/// If `template` is empty or `beat_len` is zero, then `times` will be left untouched.
+pub fn groove_quantize(
+    times: &mut [MusicalTime],
+    template: &[MusicalTime],
+    beat_len: MusicalTime,
+    strength: f32,
+) {
+    let period = i128::from(beat_len.total_ticks());
+    if template.is_empty() || period == 0 {
+        return;
+    }
+
+    let strength = f64::from(strength.clamp(0.0, 1.0));
+
+    for time in times.iter_mut() {
+        let ticks = i128::from(time.total_ticks());
+        let period_start = ticks - (ticks % period);
+
+        // Also check the slots in the neighboring periods so times near the end of a
+        // period can snap forward to the first slot of the next one.
+        let mut nearest = ticks;
+        let mut nearest_dist = i128::MAX;
+        for slot in template.iter() {
+            let slot = i128::from(slot.total_ticks()) % period;
+            for p in [period_start - period, period_start, period_start + period].iter() {
+                let target = p + slot;
+                let dist = (target - ticks).abs();
+                if target >= 0 && dist < nearest_dist {
+                    nearest = target;
+                    nearest_dist = dist;
+                }
+            }
+        }
+
+        let offset = ((nearest - ticks) as f64 * strength).round() as i128;
+        *time = MusicalTime::from_total_ticks((ticks + offset) as u64);
+    }
+}
+
 impl PartialEq for MusicalTime {
     fn eq(&self, other: &Self) -> bool {
         self.beats == other.beats && self.ticks == other.ticks
@@ -738,3 +1060,253 @@ impl MulAssign<u32> for MusicalTime {
         *self = *self * other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groove_quantize() {
+        // A simple triplet swing template.
+        let template = [
+            MusicalTime::from_beats(0),
+            MusicalTime::from_third_beats(0, 2),
+        ];
+
+        let mut times = [
+            MusicalTime::from_beats_f64(0.1),
+            MusicalTime::from_half_beats(0, 1),
+            MusicalTime::from_half_beats(2, 1),
+            MusicalTime::from_beats_f64(1.9),
+        ];
+        groove_quantize(&mut times, &template, MusicalTime::from_beats(1), 1.0);
+
+        assert_eq!(times[0], MusicalTime::from_beats(0));
+        assert_eq!(times[1], MusicalTime::from_third_beats(0, 2));
+        assert_eq!(times[2], MusicalTime::from_third_beats(2, 2));
+        assert_eq!(times[3], MusicalTime::from_beats(2));
+
+        let mut times = [MusicalTime::from_half_beats(0, 1)];
+        groove_quantize(&mut times, &template, MusicalTime::from_beats(1), 0.5);
+
+        assert_eq!(
+            times[0],
+            MusicalTime::new(0, SUPER_BEAT_TICKS_PER_BEAT / 12 * 7)
+        );
+    }
+
+    #[test]
+    fn test_coarsest_exact_division() {
+        assert_eq!(
+            MusicalTime::from_sixteenth_beats(3, 3).coarsest_exact_division(64),
+            Some(16)
+        );
+        assert_eq!(
+            MusicalTime::from_sixteenth_beats(3, 3).coarsest_exact_division(8),
+            None
+        );
+        assert_eq!(
+            MusicalTime::from_sixteenth_beats(3, 4).coarsest_exact_division(64),
+            Some(4)
+        );
+        assert_eq!(
+            MusicalTime::from_third_beats(1, 2).coarsest_exact_division(64),
+            Some(3)
+        );
+        assert_eq!(
+            MusicalTime::from_beats(5).coarsest_exact_division(64),
+            Some(1)
+        );
+        assert_eq!(MusicalTime::from_beats(5).coarsest_exact_division(0), None);
+        assert_eq!(
+            MusicalTime::new(0, 1).coarsest_exact_division(u64::MAX),
+            Some(u64::from(SUPER_BEAT_TICKS_PER_BEAT))
+        );
+    }
+
+    #[test]
+    fn test_musical_to_frame() {
+        let sample_rate = SampleRate::new(44_100.0);
+
+        for bpm in [60.0, 110.0, 120.0, 137.5].iter() {
+            let cache = MusicalToFrame::new(*bpm, sample_rate);
+
+            for time in [
+                MusicalTime::from_beats(0),
+                MusicalTime::from_beats(7),
+                MusicalTime::from_third_beats(3, 1),
+                MusicalTime::from_sixteenth_beats(1_000, 5),
+            ]
+            .iter()
+            {
+                let diff = |a: FrameTime, b: FrameTime| (a.0 as i64 - b.0 as i64).abs();
+
+                assert!(
+                    diff(
+                        cache.convert_round(*time),
+                        time.to_nearest_frame_round(*bpm, sample_rate)
+                    ) <= 1
+                );
+                assert!(
+                    diff(
+                        cache.convert_floor(*time),
+                        time.to_nearest_frame_floor(*bpm, sample_rate)
+                    ) <= 1
+                );
+                assert!(
+                    diff(
+                        cache.convert_ceil(*time),
+                        time.to_nearest_frame_ceil(*bpm, sample_rate)
+                    ) <= 1
+                );
+            }
+        }
+
+        let cache = MusicalToFrame::new(120.0, sample_rate);
+        let time = MusicalTime::from_third_beats(3, 1);
+        assert_eq!(
+            cache.convert_round(time),
+            time.to_nearest_frame_round(120.0, sample_rate)
+        );
+        assert_eq!(cache.convert_round(time), FrameTime(73_500));
+    }
+
+    #[test]
+    fn test_to_frame() {
+        let sample_rate = SampleRate::new(44_100.0);
+
+        let time = MusicalTime::from_half_beats(1, 1);
+        assert_eq!(
+            time.to_nearest_frame_round(120.0, sample_rate),
+            FrameTime(33_075)
+        );
+        assert_eq!(
+            time.to_nearest_frame_floor(120.0, sample_rate),
+            FrameTime(33_075)
+        );
+        assert_eq!(
+            time.to_nearest_frame_ceil(120.0, sample_rate),
+            FrameTime(33_075)
+        );
+
+        // 22,050 / 11 = 2,004.5454...
+        let time = MusicalTime::from_eleventh_beats(0, 1);
+        assert_eq!(
+            time.to_nearest_frame_round(120.0, sample_rate),
+            FrameTime(2_005)
+        );
+        assert_eq!(
+            time.to_nearest_frame_floor(120.0, sample_rate),
+            FrameTime(2_004)
+        );
+        assert_eq!(
+            time.to_nearest_frame_ceil(120.0, sample_rate),
+            FrameTime(2_005)
+        );
+
+        let (frame, fract) = time.to_sub_frame(120.0, sample_rate);
+        assert_eq!(frame, FrameTime(2_004));
+        assert!((fract - (6.0 / 11.0)).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_super_beats_per_frame() {
+        assert_eq!(
+            super_beats_per_frame(120.0, SampleRate::new(48_000.0)),
+            51_744.0
+        );
+
+        for (bpm, sample_rate) in [(120.0, 48_000.0), (133.0, 44_100.0), (97.3, 96_000.0)] {
+            let sample_rate = SampleRate::new(sample_rate);
+            let incr = super_beats_per_frame_q32(bpm, sample_rate);
+
+            // Accumulate over one minute (many beats), carrying whole ticks out of the
+            // fractional accumulator every frame.
+            let frames = u64::from(sample_rate.as_u32()) * 60;
+
+            let mut total_ticks: u64 = 0;
+            let mut frac: u64 = 0;
+            for _ in 0..frames {
+                frac += incr;
+                total_ticks += frac >> 32;
+                frac &= 0xFFFF_FFFF;
+            }
+
+            // This must agree exactly with a (non-overflowing) `u128` accumulator.
+            let wide = (u128::from(frames) * u128::from(incr)) >> 32;
+            assert_eq!(u128::from(total_ticks), wide);
+
+            // One minute's worth of frames should advance by `bpm` beats.
+            let expected = bpm * f64::from(SUPER_BEAT_TICKS_PER_BEAT);
+            assert!((total_ticks as f64 - expected).abs() <= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_to_sub_frame_cached() {
+        let bpm = 133.0;
+        let sample_rate = SampleRate::new(44_100.0);
+        let factor = MusicalToFrame::new(bpm, sample_rate);
+
+        for (beats, ticks) in [
+            (0, 0),
+            (1, 0),
+            (3, 1_000_000),
+            (17, 620_928_000),
+            (250, 12_345),
+        ] {
+            let time = MusicalTime::new(beats, ticks);
+
+            let (frame, frac) = time.to_sub_frame(bpm, sample_rate);
+            let (cached_frame, cached_frac) = time.to_sub_frame_cached(&factor);
+
+            assert_eq!(frame, cached_frame);
+            assert!((frac - cached_frac).abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn test_lfo_phase_to_musical() {
+        let beat = MusicalTime::new(1, 0);
+
+        assert_eq!(
+            lfo_phase_to_musical(0.25, beat),
+            MusicalTime::new(0, SUPER_BEAT_TICKS_PER_BEAT / 4)
+        );
+        assert_eq!(lfo_phase_to_musical(0.0, beat), MusicalTime::new(0, 0));
+        assert_eq!(lfo_phase_to_musical(-1.0, beat), MusicalTime::new(0, 0));
+        assert_eq!(lfo_phase_to_musical(f32::NAN, beat), MusicalTime::new(0, 0));
+        assert!(lfo_phase_to_musical(1.0, beat) < beat);
+        assert!(lfo_phase_to_musical(2.0, beat) < beat);
+
+        assert_eq!(
+            lfo_phase_to_musical(0.5, MusicalTime::new(4, 0)),
+            MusicalTime::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn test_next_beat_offset_in_block() {
+        // 120 bpm at 48 kHz is exactly 24,000 frames per beat.
+        let incr = super_beats_per_frame_q32(120.0, SampleRate::new(48_000.0));
+        let pos = MusicalTime::from_total_ticks(23_000 * 51_744);
+
+        assert_eq!(next_beat_offset_in_block(pos, incr, 1_024), Some(1_000));
+        assert_eq!(next_beat_offset_in_block(pos, incr, 1_000), None);
+        assert_eq!(
+            next_beat_offset_in_block(MusicalTime::new(3, 0), incr, 1),
+            Some(0)
+        );
+        assert_eq!(next_beat_offset_in_block(pos, 0, 1_024), None);
+
+        // Check against accumulating one frame at a time.
+        let incr = super_beats_per_frame_q32(133.0, SampleRate::new(44_100.0));
+        let pos = MusicalTime::new(7, 1_234_567_890);
+        let offset = next_beat_offset_in_block(pos, incr, 4_096).unwrap();
+
+        let start = u128::from(pos.total_ticks()) << 32;
+        let ticks_at = |frame: usize| ((start + frame as u128 * u128::from(incr)) >> 32) as u64;
+        assert!(ticks_at(offset - 1) < 8 * u64::from(SUPER_BEAT_TICKS_PER_BEAT));
+        assert!(ticks_at(offset) >= 8 * u64::from(SUPER_BEAT_TICKS_PER_BEAT));
+    }
+}
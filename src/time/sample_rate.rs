@@ -1,5 +1,8 @@
+use std::cmp::Ordering;
 use std::ops::{Div, Mul};
 
+use super::super_sample_ticks_per_sample;
+
 /// Sampling rate in samples per second.
 #[cfg_attr(feature = "serde-derive", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -38,6 +41,62 @@ impl SampleRate {
     pub fn as_usize(&self) -> usize {
         self.0.round() as usize
     }
+
+    /// Whether or not converting between this sample rate and the `other` sample rate
+    /// preserves sample accuracy.
+    ///
+    /// This is only `true` if both sample rates are one of the [`COMMON_SAMPLE_RATES`] and
+    /// the length of a sample in one rate is a whole multiple of the length of a sample in
+    /// the other (i.e. one rate is an integer multiple of the other).
+    ///
+    /// [`COMMON_SAMPLE_RATES`]: static.COMMON_SAMPLE_RATES.html
+    pub fn losslessly_convertible_to(&self, other: SampleRate) -> bool {
+        if self.0.fract() != 0.0 || other.0.fract() != 0.0 {
+            return false;
+        }
+
+        match (
+            super_sample_ticks_per_sample(self.as_u32()),
+            super_sample_ticks_per_sample(other.as_u32()),
+        ) {
+            (Some(a), Some(b)) => a % b == 0 || b % a == 0,
+            _ => false,
+        }
+    }
+
+    /// Returns the minimum of the two values.
+    ///
+    /// This uses [`f64::total_cmp`] so that the result is always well-defined. This means
+    /// that a positive `NaN` is considered greater than every other value (so it is never
+    /// returned by this method unless both values are `NaN`), and a negative `NaN` is
+    /// considered less than every other value.
+    pub fn min(self, other: Self) -> Self {
+        match other.0.total_cmp(&self.0) {
+            Ordering::Less => other,
+            _ => self,
+        }
+    }
+
+    /// Returns the maximum of the two values.
+    ///
+    /// This uses [`f64::total_cmp`] so that the result is always well-defined. This means
+    /// that a positive `NaN` is considered greater than every other value (so it is always
+    /// returned by this method if either value is a positive `NaN`), and a negative `NaN`
+    /// is considered less than every other value.
+    pub fn max(self, other: Self) -> Self {
+        match other.0.total_cmp(&self.0) {
+            Ordering::Less => self,
+            _ => other,
+        }
+    }
+
+    /// Restrict the value to the range `[min, max]`.
+    ///
+    /// This uses the same total ordering as `SampleRate::min()` and `SampleRate::max()`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min.0.total_cmp(&max.0) != Ordering::Greater);
+        self.max(min).min(max)
+    }
 }
 
 impl Default for SampleRate {
@@ -91,3 +150,23 @@ impl Div<SampleRate> for f64 {
         self / rhs.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_losslessly_convertible_to() {
+        let sr = |r: f64| SampleRate::new(r);
+
+        assert!(sr(44_100.0).losslessly_convertible_to(sr(88_200.0)));
+        assert!(sr(88_200.0).losslessly_convertible_to(sr(44_100.0)));
+        assert!(sr(48_000.0).losslessly_convertible_to(sr(192_000.0)));
+        assert!(sr(48_000.0).losslessly_convertible_to(sr(48_000.0)));
+
+        assert!(!sr(44_100.0).losslessly_convertible_to(sr(48_000.0)));
+        assert!(!sr(96_000.0).losslessly_convertible_to(sr(176_400.0)));
+        assert!(!sr(44_100.0).losslessly_convertible_to(sr(44_100.5)));
+        assert!(!sr(32_000.0).losslessly_convertible_to(sr(64_000.0)));
+    }
+}
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use super::{FrameTime, MusicalTime, SampleRate, SuperclockTime};
@@ -16,6 +17,45 @@ impl SecondsF64 {
         self.0 as f32
     }
 
+    /// Returns `true` if this value is neither infinite nor `NaN`.
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns the minimum of the two values.
+    ///
+    /// This uses [`f64::total_cmp`] so that the result is always well-defined. This means
+    /// that a positive `NaN` is considered greater than every other value (so it is never
+    /// returned by this method unless both values are `NaN`), and a negative `NaN` is
+    /// considered less than every other value.
+    pub fn min(self, other: Self) -> Self {
+        match other.0.total_cmp(&self.0) {
+            Ordering::Less => other,
+            _ => self,
+        }
+    }
+
+    /// Returns the maximum of the two values.
+    ///
+    /// This uses [`f64::total_cmp`] so that the result is always well-defined. This means
+    /// that a positive `NaN` is considered greater than every other value (so it is always
+    /// returned by this method if either value is a positive `NaN`), and a negative `NaN`
+    /// is considered less than every other value.
+    pub fn max(self, other: Self) -> Self {
+        match other.0.total_cmp(&self.0) {
+            Ordering::Less => self,
+            _ => other,
+        }
+    }
+
+    /// Restrict the value to the range `[min, max]`.
+    ///
+    /// This uses the same total ordering as `SecondsF64::min()` and `SecondsF64::max()`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min.0.total_cmp(&max.0) != Ordering::Greater);
+        self.max(min).min(max)
+    }
+
     /// Creates a new time in `Seconds` from [`FrameTime`] and a [`SampleRate`].
     ///
     /// Note that this conversion is *NOT* lossless.
@@ -42,6 +82,10 @@ impl SecondsF64 {
     ///
     /// If the seconds value is negative, then `FrameTime(0)` will be returned instead.
     ///
+    /// If the resulting number of frames is too large to fit into a `u64`, then this will
+    /// silently saturate to `FrameTime(u64::MAX)` (and a `NaN` value will become
+    /// `FrameTime(0)`). Use `SecondsF64::to_frames_checked()` if you need to detect this.
+    ///
     /// [`FrameTime`]: struct.FrameTime.html
     /// [`SampleRate`]: struct.SampleRate.html
     pub fn to_nearest_frame_round(&self, sample_rate: SampleRate) -> FrameTime {
@@ -52,6 +96,32 @@ impl SecondsF64 {
         }
     }
 
+    /// Convert to discrete [`FrameTime`] with the given [`SampleRate`]. This will
+    /// be rounded to the nearest frame.
+    ///
+    /// Unlike `SecondsF64::to_nearest_frame_round()`, this returns `None` if the seconds
+    /// value or the sample rate is not finite, or if the resulting number of frames does
+    /// not fit into a `u64`.
+    ///
+    /// If the seconds value is negative, then `Some(FrameTime(0))` will be returned instead.
+    ///
+    /// [`FrameTime`]: struct.FrameTime.html
+    /// [`SampleRate`]: struct.SampleRate.html
+    pub fn to_frames_checked(&self, sample_rate: SampleRate) -> Option<FrameTime> {
+        let frames = (self.0 * sample_rate).round();
+
+        if !frames.is_finite() {
+            None
+        } else if frames <= 0.0 {
+            Some(FrameTime(0))
+        } else if frames >= u64::MAX as f64 {
+            // `u64::MAX as f64` rounds up to 2^64, which itself does not fit into a `u64`.
+            None
+        } else {
+            Some(FrameTime(frames as u64))
+        }
+    }
+
     /// Convert to discrete [`FrameTime`] with the given [`SampleRate`]. This will
     /// be floored to the nearest frame.
     ///
@@ -59,6 +129,9 @@ impl SecondsF64 {
     ///
     /// If the seconds value is negative, then `FrameTime(0)` will be returned instead.
     ///
+    /// This assumes that the seconds value and the sample rate are finite. Use
+    /// `SecondsF64::to_frames_checked()` if you need to detect non-finite values.
+    ///
     /// [`FrameTime`]: struct.FrameTime.html
     /// [`SampleRate`]: struct.SampleRate.html
     pub fn to_nearest_frame_floor(&self, sample_rate: SampleRate) -> FrameTime {
@@ -76,6 +149,9 @@ impl SecondsF64 {
     ///
     /// If the seconds value is negative, then `FrameTime(0)` will be returned instead.
     ///
+    /// This assumes that the seconds value and the sample rate are finite. Use
+    /// `SecondsF64::to_frames_checked()` if you need to detect non-finite values.
+    ///
     /// [`FrameTime`]: struct.FrameTime.html
     /// [`SampleRate`]: struct.SampleRate.html
     pub fn to_nearest_frame_ceil(&self, sample_rate: SampleRate) -> FrameTime {
@@ -93,6 +169,8 @@ impl SecondsF64 {
     ///
     /// If the seconds value is negative, then `(FrameTime(0), 0.0)` will be returned instead.
     ///
+    /// This assumes that the seconds value and the sample rate are finite.
+    ///
     /// [`FrameTime`]: struct.FrameTime.html
     /// [`SampleRate`]: struct.SampleRate.html
     pub fn to_sub_frame(&self, sample_rate: SampleRate) -> (FrameTime, f64) {
@@ -157,10 +235,34 @@ impl SecondsF64 {
     ///
     /// Note that this conversion is *NOT* lossless.
     ///
+    /// This assumes that the seconds value and `bpm` are finite. Use
+    /// `SecondsF64::try_to_musical()` if you need to detect non-finite values.
+    ///
     /// [`MusicalTime`]: ../time/struct.MusicalTime.html
     pub fn to_musical(&self, bpm: f64) -> MusicalTime {
         MusicalTime::from_beats_f64(self.0 * (bpm / 60.0))
     }
+
+    /// Convert to the corresponding [`MusicalTime`].
+    ///
+    /// Note that this conversion is *NOT* lossless.
+    ///
+    /// Unlike `SecondsF64::to_musical()`, this returns `None` if the seconds value or `bpm`
+    /// is not finite, or if the resulting number of beats does not fit into a
+    /// [`MusicalTime`].
+    ///
+    /// If the result is negative, then `Some` musical time of `0` will be returned instead.
+    ///
+    /// [`MusicalTime`]: ../time/struct.MusicalTime.html
+    pub fn try_to_musical(&self, bpm: f64) -> Option<MusicalTime> {
+        let beats = self.0 * (bpm / 60.0);
+
+        if !beats.is_finite() || beats >= f64::from(u32::MAX) {
+            None
+        } else {
+            Some(MusicalTime::from_beats_f64(beats))
+        }
+    }
 }
 
 impl Default for SecondsF64 {
@@ -250,3 +352,85 @@ impl DivAssign<SecondsF64> for SecondsF64 {
         self.0 /= other.0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_max() {
+        let a = SecondsF64(1.0);
+        let b = SecondsF64(2.0);
+
+        assert_eq!(a.min(b), a);
+        assert_eq!(a.max(b), b);
+        assert_eq!(SecondsF64(3.0).clamp(a, b), b);
+        assert_eq!(SecondsF64(-3.0).clamp(a, b), a);
+
+        // A (positive) NaN sorts above every other value.
+        assert_eq!(SecondsF64(f64::NAN).min(a), a);
+        assert!(SecondsF64(f64::NAN).max(a).0.is_nan());
+        assert!(a.max(SecondsF64(f64::NAN)).0.is_nan());
+
+        let sr_a = SampleRate::new(44_100.0);
+        let sr_b = SampleRate::new(48_000.0);
+
+        assert_eq!(sr_a.min(sr_b), sr_a);
+        assert_eq!(sr_a.max(sr_b), sr_b);
+        assert!(sr_a.max(SampleRate(f64::NAN)).0.is_nan());
+
+        assert_eq!(FrameTime(5).max(FrameTime(7)), FrameTime(7));
+        assert_eq!(FrameTime(5).min(FrameTime(7)), FrameTime(5));
+    }
+
+    #[test]
+    fn test_to_frames_checked() {
+        let sr = SampleRate::new(48_000.0);
+
+        assert_eq!(
+            SecondsF64(1.0).to_frames_checked(sr),
+            Some(FrameTime(48_000))
+        );
+        assert_eq!(SecondsF64(-1.0).to_frames_checked(sr), Some(FrameTime(0)));
+
+        assert_eq!(SecondsF64(f64::MAX).to_frames_checked(sr), None);
+        assert_eq!(SecondsF64(f64::NAN).to_frames_checked(sr), None);
+        assert_eq!(SecondsF64(f64::INFINITY).to_frames_checked(sr), None);
+        assert_eq!(SecondsF64(1.0e15).to_frames_checked(sr), None);
+
+        // The unchecked version saturates.
+        assert_eq!(
+            SecondsF64(f64::MAX).to_nearest_frame_round(sr),
+            FrameTime(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_try_to_musical() {
+        assert!(SecondsF64(1.0).is_finite());
+        assert!(!SecondsF64(f64::INFINITY).is_finite());
+        assert!(!SecondsF64(f64::NAN).is_finite());
+
+        assert_eq!(
+            SecondsF64(1.0).try_to_musical(120.0),
+            Some(MusicalTime::new(2, 0))
+        );
+        assert_eq!(
+            SecondsF64(-1.0).try_to_musical(120.0),
+            Some(MusicalTime::new(0, 0))
+        );
+
+        assert_eq!(SecondsF64(1.0).try_to_musical(f64::NAN), None);
+        assert_eq!(SecondsF64(1.0).try_to_musical(f64::INFINITY), None);
+        assert_eq!(SecondsF64(f64::INFINITY).try_to_musical(120.0), None);
+        assert_eq!(SecondsF64(f64::NAN).try_to_musical(120.0), None);
+        assert_eq!(SecondsF64(1.0e10).try_to_musical(120.0), None);
+
+        let sr = SampleRate::new(48_000.0);
+        assert_eq!(SecondsF64(f64::INFINITY).to_frames_checked(sr), None);
+        assert_eq!(
+            SecondsF64(1.0).to_frames_checked(SampleRate(f64::NAN)),
+            None
+        );
+    }
+}
@@ -7,6 +7,36 @@ use super::{FrameTime, MusicalTime, SampleRate, SecondsF64};
 /// 384,000`. This ensures that no information is lost when switching between sample rates.
 pub static SUPER_SAMPLE_TICKS_PER_SECOND: u32 = 282_240_000;
 
+/// The common sample rates paired with the number of super-sample ticks in a single sample
+/// at that rate (`SUPER_SAMPLE_TICKS_PER_SECOND / sample_rate`).
+///
+/// Conversions between frames and [`SuperclockTime`] are lossless at these sample rates.
+///
+/// [`SuperclockTime`]: struct.SuperclockTime.html
+pub static COMMON_SAMPLE_RATES: [(u32, u64); 10] = [
+    (22_050, 12_800),
+    (24_000, 11_760),
+    (44_100, 6_400),
+    (48_000, 5_880),
+    (88_200, 3_200),
+    (96_000, 2_940),
+    (176_400, 1_600),
+    (192_000, 1_470),
+    (352_800, 800),
+    (384_000, 735),
+];
+
+/// Returns the number of super-sample ticks in a single sample at the given sample rate, or
+/// `None` if the sample rate is not one of the [`COMMON_SAMPLE_RATES`].
+///
+/// [`COMMON_SAMPLE_RATES`]: static.COMMON_SAMPLE_RATES.html
+pub fn super_sample_ticks_per_sample(sample_rate: u32) -> Option<u64> {
+    COMMON_SAMPLE_RATES
+        .iter()
+        .find(|(rate, _)| *rate == sample_rate)
+        .map(|(_, ticks)| *ticks)
+}
+
 /// Unit of time length in seconds + ticks.
 ///
 /// A "tick" is a unit of time that is exactly 1 / 282,240,000 of a second. This number
@@ -233,6 +263,10 @@ impl SuperclockTime {
     /// [`SuperclockTime`]: struct.SuperclockTime.html
     /// [`FrameTime`]: struct.FrameTime.html
     pub fn from_frame(sample: FrameTime, sample_rate: SampleRate) -> Self {
+        // This intentionally dispatches to the const-generic version instead of looking up
+        // the rate in `COMMON_SAMPLE_RATES`, so that the compiler can optimize the division
+        // by a constant. When adding a rate here, add it to `COMMON_SAMPLE_RATES` as well
+        // (and vice versa).
         match sample_rate.0 as usize {
             44_100 => Self::from_frame_with_common_framerate::<44_100>(sample),
             48_000 => Self::from_frame_with_common_framerate::<48_000>(sample),
@@ -330,6 +364,40 @@ impl SuperclockTime {
             }
         }
     }
+
+    /// Add `rhs` to self, wrapping around when the number of seconds overflows a `u32`.
+    ///
+    /// Unlike the `+` operator (which panics on overflow in debug builds), this is
+    /// intended for cyclic counters where wrapping around is the desired behavior.
+    pub fn wrapping_add(self, rhs: SuperclockTime) -> SuperclockTime {
+        let mut seconds = self.seconds.wrapping_add(rhs.seconds);
+        let mut ticks = self.ticks + rhs.ticks;
+
+        if ticks >= SUPER_SAMPLE_TICKS_PER_SECOND {
+            ticks -= SUPER_SAMPLE_TICKS_PER_SECOND;
+            seconds = seconds.wrapping_add(1);
+        }
+
+        SuperclockTime { seconds, ticks }
+    }
+
+    /// Subtract `rhs` from self, wrapping around when the number of seconds goes below
+    /// zero.
+    ///
+    /// Unlike `SuperclockTime::checked_sub()`, this never fails, and is intended for
+    /// cyclic counters where wrapping around is the desired behavior.
+    pub fn wrapping_sub(self, rhs: SuperclockTime) -> SuperclockTime {
+        let mut seconds = self.seconds.wrapping_sub(rhs.seconds);
+
+        let ticks = if rhs.ticks > self.ticks {
+            seconds = seconds.wrapping_sub(1);
+            SUPER_SAMPLE_TICKS_PER_SECOND - (rhs.ticks - self.ticks)
+        } else {
+            self.ticks - rhs.ticks
+        };
+
+        SuperclockTime { seconds, ticks }
+    }
 }
 
 impl PartialEq for SuperclockTime {
@@ -402,3 +470,84 @@ impl MulAssign<u32> for SuperclockTime {
         *self = *self * other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_sample_rates() {
+        for (rate, ticks) in COMMON_SAMPLE_RATES.iter() {
+            assert_eq!(u64::from(SUPER_SAMPLE_TICKS_PER_SECOND / *rate), *ticks);
+            assert_eq!(SUPER_SAMPLE_TICKS_PER_SECOND % *rate, 0);
+            assert_eq!(super_sample_ticks_per_sample(*rate), Some(*ticks));
+        }
+
+        assert_eq!(super_sample_ticks_per_sample(47_999), None);
+
+        // The table must agree with the rates that `SuperclockTime::from_frame()` converts
+        // losslessly.
+        for (rate, ticks) in COMMON_SAMPLE_RATES.iter() {
+            // Large enough that the lossy fallback would not produce an exact result.
+            let frame = FrameTime(u64::from(*rate) * 3_000_000_000 + u64::from(*rate) - 1);
+
+            assert_eq!(
+                SuperclockTime::from_frame(frame, SampleRate::new(f64::from(*rate))),
+                SuperclockTime::new(3_000_000_000, ((u64::from(*rate) - 1) * *ticks) as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_seconds_f64_floor_ceil() {
+        // 2 seconds + 10.25 ticks
+        let seconds = SecondsF64(2.0 + 10.25 / f64::from(SUPER_SAMPLE_TICKS_PER_SECOND));
+
+        assert_eq!(
+            SuperclockTime::from_seconds_f64_floor(seconds),
+            SuperclockTime::new(2, 10)
+        );
+        assert_eq!(
+            SuperclockTime::from_seconds_f64_ceil(seconds),
+            SuperclockTime::new(2, 11)
+        );
+        assert_eq!(
+            SuperclockTime::from_seconds_f64(seconds),
+            SuperclockTime::new(2, 10)
+        );
+
+        // An exact number of ticks is the same for all three.
+        let seconds = SecondsF64(2.5);
+        let expected = SuperclockTime::new(2, SUPER_SAMPLE_TICKS_PER_SECOND / 2);
+        assert_eq!(SuperclockTime::from_seconds_f64_floor(seconds), expected);
+        assert_eq!(SuperclockTime::from_seconds_f64_ceil(seconds), expected);
+        assert_eq!(SuperclockTime::from_seconds_f64(seconds), expected);
+
+        // Negative values are constrained to zero.
+        let zero = SuperclockTime::new(0, 0);
+        assert_eq!(
+            SuperclockTime::from_seconds_f64_floor(SecondsF64(-1.0)),
+            zero
+        );
+        assert_eq!(
+            SuperclockTime::from_seconds_f64_ceil(SecondsF64(-1.0)),
+            zero
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let zero = SuperclockTime::new(0, 0);
+        let one_tick = SuperclockTime::new(0, 1);
+        let max = SuperclockTime::new(u32::MAX, SUPER_SAMPLE_TICKS_PER_SECOND - 1);
+
+        assert_eq!(zero.wrapping_sub(one_tick), max);
+        assert_eq!(max.wrapping_add(one_tick), zero);
+
+        let a = SuperclockTime::new(3, 100);
+        let b = SuperclockTime::new(1, 200);
+        assert_eq!(a.wrapping_sub(b), a.checked_sub(b).unwrap());
+        assert_eq!(a.wrapping_add(b), a + b);
+        assert_eq!(b.wrapping_sub(a).wrapping_add(a), b);
+    }
+}